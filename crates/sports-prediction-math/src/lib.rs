@@ -0,0 +1,563 @@
+//! Pari-mutuel payout and AMM pricing arithmetic.
+//!
+//! Plain integer math with no Anchor or Solana dependency, so off-chain
+//! clients can depend on this crate alone and reproduce settlement amounts
+//! exactly. The program re-exports it as `sports_prediction_market::math`.
+//! Every operation is checked, and every division takes an explicit
+//! [`Rounding`] mode.
+
+use std::fmt;
+
+/// Denominator for amounts expressed in basis points
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Direction to round the result of a division
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Toward zero; used for anything paid out of the vault
+    Down,
+    /// Away from zero
+    Up,
+}
+
+/// Failure of a checked operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathError {
+    /// The result does not fit the target type
+    Overflow,
+    /// A divisor was zero
+    DivisionByZero,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow => f.write_str("arithmetic overflow"),
+            MathError::DivisionByZero => f.write_str("division by zero"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// Settlement of a single winning stake
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Payout {
+    /// Total returned to the bettor
+    pub payout: u64,
+    /// Platform fee charged
+    pub fee: u64,
+    /// Stake left after fees charged on the stake itself
+    pub net_stake: u64,
+}
+
+/// Computes `a * b / c` with the given rounding
+pub fn mul_div(a: u128, b: u128, c: u128, rounding: Rounding) -> Result<u128, MathError> {
+    if c == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    let product = a.checked_mul(b).ok_or(MathError::Overflow)?;
+    let quotient = product / c;
+    match rounding {
+        Rounding::Down => Ok(quotient),
+        Rounding::Up if product % c != 0 => quotient.checked_add(1).ok_or(MathError::Overflow),
+        Rounding::Up => Ok(quotient),
+    }
+}
+
+/// Computes `bps` basis points of `amount`
+pub fn apply_bps(amount: u128, bps: u32, rounding: Rounding) -> Result<u128, MathError> {
+    mul_div(amount, bps as u128, BPS_DENOMINATOR, rounding)
+}
+
+/// Sums both sides of the pool
+pub fn total_pool(win_a_amount: u128, win_b_amount: u128) -> Result<u128, MathError> {
+    win_a_amount.checked_add(win_b_amount).ok_or(MathError::Overflow)
+}
+
+/// Decimal odds implied by the pools before fees, in basis points (25000 = 2.5).
+/// Zero when nothing is staked on the winning side.
+pub fn implied_odds_bps(winning_pool: u128, losing_pool: u128) -> Result<u64, MathError> {
+    if winning_pool == 0 {
+        return Ok(0);
+    }
+    let total = total_pool(winning_pool, losing_pool)?;
+    to_u64(mul_div(total, BPS_DENOMINATOR, winning_pool, Rounding::Down)?)
+}
+
+/// Fee comes off the stake; the net stake earns its share of the losing pool
+pub fn stake_fee_payout(
+    stake: u64,
+    fee_bps: u32,
+    winning_pool: u128,
+    losing_pool: u128,
+) -> Result<Payout, MathError> {
+    if winning_pool == 0 {
+        return Ok(Payout::default());
+    }
+    let stake = stake as u128;
+    let fee = apply_bps(stake, fee_bps, Rounding::Down)?;
+    let net_stake = stake.checked_sub(fee).ok_or(MathError::Overflow)?;
+    let share = mul_div(net_stake, losing_pool, winning_pool, Rounding::Down)?;
+    let payout = net_stake.checked_add(share).ok_or(MathError::Overflow)?;
+
+    Ok(Payout {
+        payout: to_u64(payout)?,
+        fee: to_u64(fee)?,
+        net_stake: to_u64(net_stake)?,
+    })
+}
+
+/// Full stake earns its share of the losing pool; the fee comes off the profit only
+pub fn net_winnings_payout(
+    stake: u64,
+    fee_bps: u32,
+    winning_pool: u128,
+    losing_pool: u128,
+) -> Result<Payout, MathError> {
+    if winning_pool == 0 {
+        return Ok(Payout::default());
+    }
+    let stake = stake as u128;
+    let share = mul_div(stake, losing_pool, winning_pool, Rounding::Down)?;
+    let fee = apply_bps(share, fee_bps, Rounding::Down)?;
+    let payout = stake.checked_add(share)
+        .and_then(|gross| gross.checked_sub(fee))
+        .ok_or(MathError::Overflow)?;
+
+    Ok(Payout {
+        payout: to_u64(payout)?,
+        fee: to_u64(fee)?,
+        net_stake: to_u64(stake)?,
+    })
+}
+
+/// House take comes off the total pool; the rest is split pro rata
+pub fn pool_take_payout(
+    stake: u64,
+    fee_bps: u32,
+    winning_pool: u128,
+    losing_pool: u128,
+) -> Result<Payout, MathError> {
+    if winning_pool == 0 {
+        return Ok(Payout::default());
+    }
+    let total = total_pool(winning_pool, losing_pool)?;
+    let take = apply_bps(total, fee_bps, Rounding::Down)?;
+    let distributable = total.checked_sub(take).ok_or(MathError::Overflow)?;
+    let stake = stake as u128;
+    let gross = mul_div(stake, total, winning_pool, Rounding::Down)?;
+    let payout = mul_div(stake, distributable, winning_pool, Rounding::Down)?;
+    let fee = gross.checked_sub(payout).ok_or(MathError::Overflow)?;
+
+    Ok(Payout {
+        payout: to_u64(payout)?,
+        fee: to_u64(fee)?,
+        net_stake: to_u64(stake)?,
+    })
+}
+
+/// Outcome shares bought from a constant-product pool holding `side` shares of
+/// the chosen outcome and `other` of the opposite one. The investment mints
+/// that many complete sets into the pool, then the pool releases enough of
+/// `side` to restore `side * other`. The pool keeps the rounding.
+pub fn cpmm_buy(side: u64, other: u64, investment: u64) -> Result<u64, MathError> {
+    let invariant = (side as u128).checked_mul(other as u128).ok_or(MathError::Overflow)?;
+    let new_side = (side as u128).checked_add(investment as u128).ok_or(MathError::Overflow)?;
+    let new_other = (other as u128).checked_add(investment as u128).ok_or(MathError::Overflow)?;
+    let remaining_side = mul_div(invariant, 1, new_other, Rounding::Up)?;
+    to_u64(new_side.checked_sub(remaining_side).ok_or(MathError::Overflow)?)
+}
+
+/// Collateral released by selling `shares_in` of an outcome back to the pool.
+/// The pool burns `c` complete sets, choosing the largest `c` that keeps
+/// `side * other` from decreasing, so the result rounds down.
+pub fn cpmm_sell(side: u64, other: u64, shares_in: u64) -> Result<u64, MathError> {
+    let invariant = (side as u128).checked_mul(other as u128).ok_or(MathError::Overflow)?;
+    let new_side = (side as u128).checked_add(shares_in as u128).ok_or(MathError::Overflow)?;
+    let other = other as u128;
+
+    // The remaining product only shrinks as more sets are burned, so binary search
+    let (mut low, mut high) = (0u128, other.min(new_side));
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        let product = (new_side - mid).checked_mul(other - mid).ok_or(MathError::Overflow)?;
+        if product >= invariant {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    to_u64(low)
+}
+
+/// Share of a scalar market's pool owed to the long side when it resolves at
+/// `value` on `[min, max]`. The value is clamped to the range and the long
+/// share rounds down; the short side gets the rest.
+pub fn scalar_long_pot(total_pool: u128, value: i64, min: i64, max: i64) -> Result<u128, MathError> {
+    let span = (max as i128 - min as i128) as u128;
+    let above_min = (value.clamp(min, max) as i128 - min as i128) as u128;
+    mul_div(total_pool, above_min, span, Rounding::Down)
+}
+
+/// A scalar bettor's pro-rata share of their side's pot, with the fee taken
+/// off the gross amount
+pub fn scalar_payout(
+    stake: u64,
+    fee_bps: u32,
+    side_pool: u128,
+    side_pot: u128,
+) -> Result<Payout, MathError> {
+    if side_pool == 0 {
+        return Ok(Payout::default());
+    }
+    let gross = mul_div(stake as u128, side_pot, side_pool, Rounding::Down)?;
+    let fee = apply_bps(gross, fee_bps, Rounding::Down)?;
+    Ok(Payout {
+        payout: to_u64(gross - fee)?,
+        fee: to_u64(fee)?,
+        net_stake: stake,
+    })
+}
+
+/// Liability a layer escrows to match `back_stake` at decimal odds `odds_bps`:
+/// the backer's winnings, rounded down
+pub fn lay_liability(back_stake: u64, odds_bps: u32) -> Result<u64, MathError> {
+    let winnings_bps = (odds_bps as u128).checked_sub(BPS_DENOMINATOR).ok_or(MathError::Overflow)?;
+    to_u64(mul_div(back_stake as u128, winnings_bps, BPS_DENOMINATOR, Rounding::Down)?)
+}
+
+/// Weight of a stake placed at `now`, in basis points: full weight until
+/// `decay_starts_at`, then falling linearly to `floor_bps` at `closes_at`
+pub fn stake_weight_bps(now: i64, decay_starts_at: i64, closes_at: i64, floor_bps: u32) -> u32 {
+    if now <= decay_starts_at {
+        return BPS_DENOMINATOR as u32;
+    }
+    if now >= closes_at {
+        return floor_bps;
+    }
+    let elapsed = (now as i128 - decay_starts_at as i128) as u128;
+    let window = (closes_at as i128 - decay_starts_at as i128) as u128;
+    let decay = (BPS_DENOMINATOR - floor_bps as u128) * elapsed / window;
+    (BPS_DENOMINATOR - decay) as u32
+}
+
+/// A winner's payout when the losing pool is split by time-weighted stake:
+/// the stake back plus `weight / total_weight` of the losing pool. The fee
+/// comes off that share alone when `fee_on_winnings_only`, otherwise off the
+/// whole payout.
+pub fn weighted_payout(
+    stake: u64,
+    weight: u64,
+    fee_bps: u32,
+    fee_on_winnings_only: bool,
+    total_weight: u128,
+    losing_pool: u128,
+) -> Result<Payout, MathError> {
+    if total_weight == 0 {
+        return Ok(Payout::default());
+    }
+    let stake = stake as u128;
+    let share = mul_div(weight as u128, losing_pool, total_weight, Rounding::Down)?;
+    let gross = stake.checked_add(share).ok_or(MathError::Overflow)?;
+    let fee = apply_bps(if fee_on_winnings_only { share } else { gross }, fee_bps, Rounding::Down)?;
+
+    Ok(Payout {
+        payout: to_u64(gross - fee)?,
+        fee: to_u64(fee)?,
+        net_stake: to_u64(stake)?,
+    })
+}
+
+/// Value of a pari-mutuel stake cashed out before the result: what it would
+/// pay if its side won, times the probability the rest of the pool implies
+/// for that side, less `cash_out_fee_bps`. The bet's own stake is left out of
+/// that probability, so a bettor cannot move the price and then exit at it.
+/// A stake that is alone in the pool is valued at its payout if won.
+pub fn cash_out_value(
+    if_won: u64,
+    stake: u64,
+    side_pool: u128,
+    other_pool: u128,
+    cash_out_fee_bps: u32,
+) -> Result<u64, MathError> {
+    let rest_of_side = side_pool.checked_sub(stake as u128).ok_or(MathError::Overflow)?;
+    let rest_of_pool = total_pool(rest_of_side, other_pool)?;
+    let value = if rest_of_pool == 0 {
+        if_won as u128
+    } else {
+        mul_div(if_won as u128, rest_of_side, rest_of_pool, Rounding::Down)?
+    };
+    let fee = apply_bps(value, cash_out_fee_bps, Rounding::Up)?;
+    to_u64(value - fee)
+}
+
+fn to_u64(value: u128) -> Result<u64, MathError> {
+    u64::try_from(value).map_err(|_| MathError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    type PayoutFn = fn(u64, u32, u128, u128) -> Result<Payout, MathError>;
+
+    const MODES: [PayoutFn; 3] = [stake_fee_payout, net_winnings_payout, pool_take_payout];
+
+    #[test]
+    fn mul_div_rounds_in_the_requested_direction() {
+        assert_eq!(mul_div(10, 1, 3, Rounding::Down), Ok(3));
+        assert_eq!(mul_div(10, 1, 3, Rounding::Up), Ok(4));
+        assert_eq!(mul_div(9, 1, 3, Rounding::Up), Ok(3));
+        assert_eq!(mul_div(1, 1, 0, Rounding::Down), Err(MathError::DivisionByZero));
+        assert_eq!(mul_div(u128::MAX, 2, 1, Rounding::Down), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn total_pool_is_checked() {
+        assert_eq!(total_pool(u128::MAX, 1), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn empty_winning_side_pays_nothing() {
+        for mode in MODES {
+            assert_eq!(mode(100, 500, 0, 1_000), Ok(Payout::default()));
+        }
+    }
+
+    prop_compose! {
+        /// Winning stakes plus a losing pool and fee rate
+        fn market()(
+            stakes in prop::collection::vec(1..=u64::MAX / 64, 1..16),
+            losing_pool in 0..=u64::MAX / 4,
+            fee_bps in 0..=10_000u32,
+        ) -> (Vec<u64>, u128, u32) {
+            (stakes, losing_pool as u128, fee_bps)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn payouts_and_fees_never_exceed_the_pool((stakes, losing_pool, fee_bps) in market()) {
+            let winning_pool: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+            for mode in MODES {
+                let mut distributed = 0u128;
+                for &stake in &stakes {
+                    let settlement = mode(stake, fee_bps, winning_pool, losing_pool).unwrap();
+                    prop_assert!(settlement.net_stake <= stake);
+                    distributed += settlement.payout as u128 + settlement.fee as u128;
+                }
+                prop_assert!(distributed <= winning_pool + losing_pool);
+            }
+        }
+
+        #[test]
+        fn rounding_dust_is_bounded_without_fees((stakes, losing_pool, _fee_bps) in market()) {
+            let winning_pool: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+            for mode in MODES {
+                let distributed: u128 = stakes
+                    .iter()
+                    .map(|&stake| mode(stake, 0, winning_pool, losing_pool).unwrap().payout as u128)
+                    .sum();
+                // Each winner loses less than one unit to rounding
+                prop_assert!(winning_pool + losing_pool - distributed < stakes.len() as u128);
+            }
+        }
+
+        #[test]
+        fn payout_grows_with_stake(
+            stake in 0..=u64::MAX / 4,
+            extra in 0..=u64::MAX / 4,
+            other_stakes in 0..=u64::MAX / 4,
+            losing_pool in 0..=u64::MAX / 4,
+            fee_bps in 0..=10_000u32,
+        ) {
+            let winning_pool = stake as u128 + extra as u128 + other_stakes as u128 + 1;
+            let losing_pool = losing_pool as u128;
+            for mode in MODES {
+                let smaller = mode(stake, fee_bps, winning_pool, losing_pool).unwrap();
+                let larger = mode(stake + extra, fee_bps, winning_pool, losing_pool).unwrap();
+                prop_assert!(smaller.payout <= larger.payout);
+            }
+        }
+
+        #[test]
+        fn payout_shrinks_as_fee_rises(
+            stake in 1..=u64::MAX / 4,
+            other_stakes in 0..=u64::MAX / 4,
+            losing_pool in 0..=u64::MAX / 4,
+            fee_bps in 0..=10_000u32,
+            fee_increase in 0..=10_000u32,
+        ) {
+            let winning_pool = stake as u128 + other_stakes as u128;
+            let losing_pool = losing_pool as u128;
+            let higher_fee = (fee_bps + fee_increase).min(10_000);
+            for mode in MODES {
+                let cheap = mode(stake, fee_bps, winning_pool, losing_pool).unwrap();
+                let expensive = mode(stake, higher_fee, winning_pool, losing_pool).unwrap();
+                prop_assert!(expensive.payout <= cheap.payout);
+            }
+        }
+
+        #[test]
+        fn payout_grows_with_losing_pool(
+            stake in 1..=u64::MAX / 4,
+            other_stakes in 0..=u64::MAX / 4,
+            losing_pool in 0..=u64::MAX / 4,
+            extra in 0..=u64::MAX / 4,
+            fee_bps in 0..=10_000u32,
+        ) {
+            let winning_pool = stake as u128 + other_stakes as u128;
+            for mode in MODES {
+                let before = mode(stake, fee_bps, winning_pool, losing_pool as u128).unwrap();
+                let after = mode(stake, fee_bps, winning_pool, losing_pool as u128 + extra as u128).unwrap();
+                prop_assert!(before.payout <= after.payout);
+            }
+        }
+
+        #[test]
+        fn cpmm_round_trip_never_profits(
+            side in 1..=u64::MAX / 4,
+            other in 1..=u64::MAX / 4,
+            investment in 0..=u64::MAX / 4,
+        ) {
+            let shares = cpmm_buy(side, other, investment).unwrap();
+            let new_side = side + investment - shares;
+            let new_other = other + investment;
+            // Buying never shrinks the pool's invariant
+            prop_assert!(new_side as u128 * new_other as u128 >= side as u128 * other as u128);
+
+            let returned = cpmm_sell(new_side, new_other, shares).unwrap();
+            prop_assert!(returned <= investment);
+        }
+
+        #[test]
+        fn cpmm_sell_keeps_the_invariant(
+            side in 1..=u64::MAX / 4,
+            other in 1..=u64::MAX / 4,
+            shares_in in 0..=u64::MAX / 4,
+        ) {
+            let collateral = cpmm_sell(side, other, shares_in).unwrap() as u128;
+            let (side, other) = (side as u128, other as u128);
+            prop_assert!(collateral <= other);
+            prop_assert!((side + shares_in as u128 - collateral) * (other - collateral) >= side * other);
+        }
+
+        #[test]
+        fn scalar_payouts_never_exceed_the_pool(
+            (stakes, _losing_pool, fee_bps) in market(),
+            short_stakes in prop::collection::vec(1..=u64::MAX / 64, 0..8),
+            value in any::<i64>(),
+            min in -1_000_000i64..1_000_000,
+            span in 1i64..1_000_000,
+        ) {
+            let long_pool: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+            let short_pool: u128 = short_stakes.iter().map(|&stake| stake as u128).sum();
+            let total = long_pool + short_pool;
+            let long_pot = scalar_long_pot(total, value, min, min + span).unwrap();
+            prop_assert!(long_pot <= total);
+
+            let mut distributed = 0u128;
+            for &stake in &stakes {
+                let settlement = scalar_payout(stake, fee_bps, long_pool, long_pot).unwrap();
+                distributed += settlement.payout as u128 + settlement.fee as u128;
+            }
+            for &stake in &short_stakes {
+                let settlement = scalar_payout(stake, fee_bps, short_pool, total - long_pot).unwrap();
+                distributed += settlement.payout as u128 + settlement.fee as u128;
+            }
+            prop_assert!(distributed <= total);
+        }
+
+        #[test]
+        fn stake_weight_decays_from_full_to_the_floor(
+            start in -1_000_000i64..1_000_000,
+            window in 0i64..1_000_000,
+            now in -2_000_000i64..3_000_000,
+            later in 0i64..1_000_000,
+            floor_bps in 1..=10_000u32,
+        ) {
+            let closes = start + window;
+            let weight = stake_weight_bps(now, start, closes, floor_bps);
+            prop_assert!((floor_bps..=10_000).contains(&weight));
+            prop_assert!(stake_weight_bps(now + later, start, closes, floor_bps) <= weight);
+        }
+
+        #[test]
+        fn weighted_payouts_never_exceed_the_pool(
+            (stakes, losing_pool, fee_bps) in market(),
+            weight_bps in prop::collection::vec(1..=10_000u32, 16),
+            fee_on_winnings_only in any::<bool>(),
+        ) {
+            let winning_pool: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+            let weights: Vec<u64> = stakes
+                .iter()
+                .zip(&weight_bps)
+                .map(|(&stake, &bps)| apply_bps(stake as u128, bps, Rounding::Up).unwrap() as u64)
+                .collect();
+            let total_weight: u128 = weights.iter().map(|&weight| weight as u128).sum();
+
+            let mut distributed = 0u128;
+            for (&stake, &weight) in stakes.iter().zip(&weights) {
+                let settlement = weighted_payout(
+                    stake, weight, fee_bps, fee_on_winnings_only, total_weight, losing_pool,
+                ).unwrap();
+                prop_assert!(settlement.payout as u128 + settlement.fee as u128 >= stake as u128);
+                distributed += settlement.payout as u128 + settlement.fee as u128;
+            }
+            prop_assert!(distributed <= winning_pool + losing_pool);
+        }
+
+        #[test]
+        fn cash_out_never_exceeds_the_stake(
+            stake in 1..=u64::MAX / 64,
+            rest_of_side in 0..=u64::MAX / 64,
+            other_pool in 0..=u64::MAX / 64,
+            fee_bps in 0..=10_000u32,
+            cash_out_fee_bps in 0..=10_000u32,
+            more in 1..=u64::MAX / 64,
+        ) {
+            let side_pool = stake as u128 + rest_of_side as u128;
+            let other_pool = other_pool as u128;
+            for mode in MODES {
+                let if_won = mode(stake, fee_bps, side_pool, other_pool).unwrap().payout;
+                let value = cash_out_value(if_won, stake, side_pool, other_pool, cash_out_fee_bps).unwrap();
+                prop_assert!(value <= stake);
+
+                // Backing from other bettors on the same side raises the value
+                let backed_side = side_pool + more as u128;
+                let backed_if_won = mode(stake, fee_bps, backed_side, other_pool).unwrap().payout;
+                let backed = cash_out_value(backed_if_won, stake, backed_side, other_pool, 0).unwrap();
+                let unbacked = cash_out_value(if_won, stake, side_pool, other_pool, 0).unwrap();
+                prop_assert!(rest_of_side == 0 && other_pool == 0 || backed + 1 >= unbacked);
+            }
+        }
+
+        #[test]
+        fn partial_fills_never_need_more_lay_escrow(
+            stake in 0..=u64::MAX / 8,
+            filled in 0..=u64::MAX / 8,
+            odds_bps in 10_001..=80_000u32,
+        ) {
+            let filled = filled.min(stake);
+            let whole = lay_liability(stake, odds_bps).unwrap();
+            let parts = lay_liability(filled, odds_bps).unwrap() as u128
+                + lay_liability(stake - filled, odds_bps).unwrap() as u128;
+            prop_assert!(parts <= whole as u128);
+        }
+
+        #[test]
+        fn rounding_up_exceeds_rounding_down_by_at_most_one(
+            a in any::<u64>(),
+            b in any::<u64>(),
+            c in 1..=u64::MAX,
+        ) {
+            let (a, b, c) = (a as u128, b as u128, c as u128);
+            let down = mul_div(a, b, c, Rounding::Down).unwrap();
+            let up = mul_div(a, b, c, Rounding::Up).unwrap();
+            prop_assert!(up - down <= 1);
+            prop_assert_eq!(up == down, a * b % c == 0);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::math::MathError;
 
 #[error_code]
 pub enum Error {
@@ -32,4 +33,100 @@ pub enum Error {
     InvalidStringLength,
     #[msg("Insufficient funds in vault")]
     InsufficientFunds,
+    #[msg("Invalid bet limits")]
+    InvalidBetLimits,
+    #[msg("Bet amount is below the event minimum")]
+    BetBelowMinimum,
+    #[msg("Bet amount is above the event maximum")]
+    BetAboveMaximum,
+    #[msg("Wallet exposure limit exceeded")]
+    WalletExposureExceeded,
+    #[msg("Existing bet is on a different outcome")]
+    OutcomeMismatch,
+    #[msg("Invalid risk limits")]
+    InvalidRiskLimits,
+    #[msg("Bet would exceed the pool cap for this side")]
+    SidePoolCapExceeded,
+    #[msg("Bet would exceed the total pool cap")]
+    TotalPoolCapExceeded,
+    #[msg("Betting is already open")]
+    BettingAlreadyOpen,
+    #[msg("Invalid fee split")]
+    InvalidFeeSplit,
+    #[msg("Invalid fee recipient account")]
+    InvalidFeeRecipient,
+    #[msg("Accrued fees must be withdrawn first")]
+    FeesNotWithdrawn,
+    #[msg("No fees to withdraw")]
+    NoFeesToWithdraw,
+    #[msg("Pool accounting invariant violated")]
+    InvariantViolation,
+    #[msg("Remaining accounts must be (bet, destination) pairs")]
+    InvalidBatch,
+    #[msg("Payout destination does not belong to the bet owner")]
+    InvalidPayoutDestination,
+    #[msg("Invalid payout root")]
+    InvalidPayoutRoot,
+    #[msg("Invalid merkle proof")]
+    InvalidMerkleProof,
+    #[msg("Payout already claimed")]
+    PayoutAlreadyClaimed,
+    #[msg("Event is settled through merkle claims")]
+    MerkleSettlementActive,
+    #[msg("Instruction is not supported for this market type")]
+    InvalidMarketType,
+    #[msg("Invalid odds")]
+    InvalidOdds,
+    #[msg("Bet exceeds the bankroll's capacity")]
+    BankrollExceeded,
+    #[msg("Trade would fill outside the slippage limit")]
+    SlippageExceeded,
+    #[msg("Not enough shares")]
+    InsufficientShares,
+    #[msg("Cannot match your own offer")]
+    SelfMatch,
+    #[msg("Offer does not have enough unmatched stake")]
+    OfferExhausted,
+    #[msg("Cash-out would push the pool past its imbalance limit")]
+    CashOutImbalance,
+    #[msg("Bet cannot be transferred to its current owner")]
+    InvalidBetRecipient,
+    #[msg("Parlay legs must be distinct open events with one pick each")]
+    InvalidParlay,
+    #[msg("Market does not belong to this fixture")]
+    InvalidFixture,
+    #[msg("Scalar range must have min below max")]
+    InvalidScalarRange,
+    #[msg("Fixture has already been completed or cancelled")]
+    FixtureNotScheduled,
+    #[msg("Bracket matches must be linked within one tournament")]
+    InvalidBracket,
+    #[msg("Entrant is not in the tournament")]
+    InvalidEntrant,
+    #[msg("Invalid stake weighting curve")]
+    InvalidStakeWeighting,
+    #[msg("Amount exceeds the promo budget that is not reserved for bonuses")]
+    PromoBudgetExceeded,
+    #[msg("Early-bird bonuses must be settled per bet")]
+    PromoActive,
+    #[msg("Bets on this event must be committed and revealed")]
+    PrivateBetting,
+    #[msg("Reveal does not match the commitment")]
+    InvalidCommitment,
+    #[msg("Reveal window is not open")]
+    RevealNotOpen,
+    #[msg("Reveal window has not ended")]
+    RevealPending,
+    #[msg("Reveal window must be positive")]
+    InvalidRevealWindow,
+    #[msg("Event still has unsettled parlays")]
+    OpenParlays,
+    #[msg("Fixture markets are resolved through their fixture")]
+    FixtureMarket,
+}
+
+impl From<MathError> for Error {
+    fn from(_: MathError) -> Self {
+        Error::OverflowError
+    }
 }
@@ -2,14 +2,126 @@
 
 pub mod create_event;
 pub mod create_bet;
+pub mod update_bet_limits;
+pub mod update_risk_limits;
+pub mod set_fee_split;
 pub mod close_betting;
+pub mod resume_betting;
 pub mod announce_winner;
 pub mod settle_bet;
+pub mod withdraw_fees;
+pub mod quote_payout;
+pub mod quote_settlement;
+pub mod settle_bets_batch;
+pub mod post_payout_root;
+pub mod claim_with_proof;
+pub mod set_odds;
+pub mod fund_bankroll;
+pub mod withdraw_bankroll;
+pub mod seed_liquidity;
+pub mod buy_shares;
+pub mod sell_shares;
+pub mod redeem_shares;
+pub mod withdraw_liquidity;
+pub mod enable_conditional_tokens;
+pub mod split_position;
+pub mod merge_positions;
+pub mod redeem_outcome_tokens;
+pub mod post_offer;
+pub mod match_offer;
+pub mod cancel_offer;
+pub mod settle_matched_bet;
+pub mod refund_matched_bet;
+pub mod cash_out;
+pub mod set_cash_out_fee;
+pub mod transfer_bet;
+pub mod void_event;
+pub mod create_parlay_pool;
+pub mod fund_parlay_pool;
+pub mod withdraw_parlay_pool;
+pub mod place_parlay;
+pub mod settle_parlay;
+pub mod set_market_kind;
+pub mod report_score;
+pub mod resolve_scalar;
+pub mod create_fixture;
+pub mod add_fixture_market;
+pub mod cancel_fixture;
+pub mod create_tournament;
+pub mod link_bracket_match;
+pub mod set_tournament_final;
+pub mod place_outright_bet;
+pub mod claim_outright;
+pub mod void_tournament;
+pub mod set_stake_weighting;
+pub mod set_early_bird;
+pub mod fund_promo;
+pub mod withdraw_promo;
+pub mod set_private_betting;
+pub mod commit_bet;
+pub mod reveal_bet;
+pub mod expire_commitment;
 pub mod close_event;
 
 pub use create_event::*;
 pub use create_bet::*;
+pub use update_bet_limits::*;
+pub use update_risk_limits::*;
+pub use set_fee_split::*;
 pub use close_betting::*;
+pub use resume_betting::*;
 pub use announce_winner::*;
 pub use settle_bet::*;
+pub use withdraw_fees::*;
+pub use quote_payout::*;
+pub use quote_settlement::*;
+pub use settle_bets_batch::*;
+pub use post_payout_root::*;
+pub use claim_with_proof::*;
+pub use set_odds::*;
+pub use fund_bankroll::*;
+pub use withdraw_bankroll::*;
+pub use seed_liquidity::*;
+pub use buy_shares::*;
+pub use sell_shares::*;
+pub use redeem_shares::*;
+pub use withdraw_liquidity::*;
+pub use enable_conditional_tokens::*;
+pub use split_position::*;
+pub use merge_positions::*;
+pub use redeem_outcome_tokens::*;
+pub use post_offer::*;
+pub use match_offer::*;
+pub use cancel_offer::*;
+pub use settle_matched_bet::*;
+pub use refund_matched_bet::*;
+pub use cash_out::*;
+pub use set_cash_out_fee::*;
+pub use transfer_bet::*;
+pub use void_event::*;
+pub use create_parlay_pool::*;
+pub use fund_parlay_pool::*;
+pub use withdraw_parlay_pool::*;
+pub use place_parlay::*;
+pub use settle_parlay::*;
+pub use set_market_kind::*;
+pub use report_score::*;
+pub use resolve_scalar::*;
+pub use create_fixture::*;
+pub use add_fixture_market::*;
+pub use cancel_fixture::*;
+pub use create_tournament::*;
+pub use link_bracket_match::*;
+pub use set_tournament_final::*;
+pub use place_outright_bet::*;
+pub use claim_outright::*;
+pub use void_tournament::*;
+pub use set_stake_weighting::*;
+pub use set_early_bird::*;
+pub use fund_promo::*;
+pub use withdraw_promo::*;
+pub use set_private_betting::*;
+pub use commit_bet::*;
+pub use reveal_bet::*;
+pub use expire_commitment::*;
 pub use close_event::*;
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Fixture, FixtureStatus, MarketKind, MarketType, Outcome};
+use crate::error::Error;
+
+/// Attaches a standalone event to the fixture as one of its child markets.
+/// Only allowed before the first bet, since it changes how the market resolves.
+pub fn add_fixture_market(
+    ctx: Context<AddFixtureMarket>,
+) -> Result<()> {
+    let fixture = &mut ctx.accounts.fixture;
+    let event = &mut ctx.accounts.event;
+
+    require!(event.win_a_count == 0 && event.win_b_count == 0, Error::InvalidFixture);
+    require!(event.fixture == Pubkey::default(), Error::InvalidFixture);
+    // Share markets and the exchange cannot be voided if the fixture is cancelled
+    require!(
+        matches!(event.market_type, MarketType::Parimutuel | MarketType::FixedOdds),
+        Error::InvalidMarketType
+    );
+    // Scores resolve fixture markets without advancing a bracket
+    require!(event.tournament == Pubkey::default(), Error::InvalidFixture);
+
+    fixture.market_count = fixture.market_count.checked_add(1)
+        .ok_or(Error::OverflowError)?;
+    event.fixture = fixture.key();
+
+    emit!(FixtureMarketAdded {
+        fixture: fixture.key(),
+        fixture_id: fixture.fixture_id,
+        event: event.key(),
+        market_kind: event.market_kind,
+        market_count: fixture.market_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddFixtureMarket<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = fixture.authority == authority.key() @ Error::Unauthorized,
+        constraint = fixture.status == FixtureStatus::Scheduled @ Error::FixtureNotScheduled,
+    )]
+    pub fixture: Account<'info, Fixture>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct FixtureMarketAdded {
+    pub fixture: Pubkey,
+    pub fixture_id: u64,
+    pub event: Pubkey,
+    pub market_kind: MarketKind,
+    pub market_count: u16,
+}
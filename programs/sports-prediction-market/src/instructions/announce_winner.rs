@@ -1,19 +1,123 @@
 use anchor_lang::prelude::*;
-use crate::state::{Event, Outcome};
+use crate::state::{Event, MarketType, Outcome, Tournament};
 use crate::error::Error;
+use crate::instructions::SettlementFinalized;
 
-pub fn announce_winner(
-    ctx: Context<AnnounceWinner>,
+/// Resolves the event. For a bracket match, remaining accounts are its
+/// tournament, then the match its winner advances to (both writable); the
+/// final instead resolves the tournament's outright pool, unless the
+/// tournament was voided.
+pub fn announce_winner<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AnnounceWinner<'info>>,
     winner: Outcome,
 ) -> Result<()> {
     let event = &mut ctx.accounts.event;
-    
+    record_winner(event, winner)?;
+    advance_bracket(event, ctx.remaining_accounts)
+}
+
+/// Carries the winner of a bracket match into its next match, or crowns the
+/// champion if it was the final
+fn advance_bracket<'info>(
+    event: &Account<'info, Event>,
+    infos: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    if event.tournament == Pubkey::default() {
+        return Ok(());
+    }
+
+    let winner_name = if event.outcome == Outcome::WinA {
+        event.opponent_a.clone()
+    } else {
+        event.opponent_b.clone()
+    };
+    let mut infos = infos.iter();
+    let tournament_info = infos.next().ok_or(Error::InvalidBracket)?;
+    require_keys_eq!(tournament_info.key(), event.tournament, Error::InvalidBracket);
+
+    if event.next_event != Pubkey::default() {
+        let next_info = infos.next().ok_or(Error::InvalidBracket)?;
+        require_keys_eq!(next_info.key(), event.next_event, Error::InvalidBracket);
+        let mut next_event: Account<'info, Event> = Account::try_from(next_info)?;
+        require!(next_event.outcome == Outcome::Undrawn, Error::EventSettled);
+
+        if event.next_slot == 0 {
+            next_event.opponent_a = winner_name.clone();
+        } else {
+            next_event.opponent_b = winner_name.clone();
+        }
+
+        emit!(BracketAdvanced {
+            tournament: event.tournament,
+            event: event.key(),
+            next_event: next_event.key(),
+            slot: event.next_slot,
+            winner: winner_name,
+        });
+
+        next_event.exit(&crate::ID)?;
+    } else {
+        let mut tournament: Account<'info, Tournament> = Account::try_from(tournament_info)?;
+        require_keys_eq!(tournament.final_event, event.key(), Error::InvalidBracket);
+        // Outright stakes are already being refunded
+        if tournament.voided {
+            return Ok(());
+        }
+        let champion = tournament.entrant_index(&winner_name)
+            .ok_or(Error::InvalidEntrant)?;
+        tournament.champion = Some(champion);
+
+        emit!(TournamentResolved {
+            tournament: tournament.key(),
+            champion,
+            winner: winner_name,
+            winning_pool: tournament.entrant_pools[champion as usize],
+            total_pool: tournament.total_pool,
+        });
+
+        tournament.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Resolves `event` in favor of `winner` and books what each side is now owed
+pub fn record_winner(event: &mut Account<Event>, winner: Outcome) -> Result<()> {
     // Validate inputs
     require!(winner == Outcome::WinA || winner == Outcome::WinB, Error::InvalidOutcome);
     require!(event.outcome == Outcome::Undrawn, Error::EventSettled);
+    require!(!event.market_kind.is_scalar(), Error::InvalidMarketType);
+    require!(!event.reveal_pending(Clock::get()?.unix_timestamp), Error::RevealPending);
     
     // Set the winner
     event.outcome = winner;
+
+    if event.market_type == MarketType::FixedOdds {
+        // Winners are owed their locked-in payouts; whatever is left belongs to the house
+        let owed = if winner == Outcome::WinA { event.liability_a } else { event.liability_b };
+        let bankroll = (event.bankroll as u128)
+            .checked_add(event.win_a_amount)
+            .and_then(|funds| funds.checked_add(event.win_b_amount))
+            .and_then(|funds| funds.checked_sub(owed as u128))
+            .ok_or(Error::InvariantViolation)?;
+        event.bankroll = u64::try_from(bankroll).map_err(|_| Error::OverflowError)?;
+        event.outstanding_payouts = owed;
+    } else {
+        // Losing stakes are no longer owed back; they become the winners' share
+        let (winning_pool, losing_pool) = if winner == Outcome::WinA {
+            (event.win_a_amount, event.win_b_amount)
+        } else {
+            (event.win_b_amount, event.win_a_amount)
+        };
+        if event.refunds_all_stakes() {
+            // Nobody backed the winner, so the losing side is refunded
+            event.outstanding_stake = losing_pool;
+            event.outstanding_losing_share = 0;
+        } else {
+            event.outstanding_stake = winning_pool;
+            event.outstanding_losing_share = losing_pool;
+        }
+    }
     
     emit!(WinnerAnnounced {
         event: event.key(),
@@ -21,6 +125,17 @@ pub fn announce_winner(
         win_a_amount: event.win_a_amount,
         win_b_amount: event.win_b_amount,
     });
+
+    // With no bets at all there is nothing to claim
+    if let Some(dust) = event.finalize_settlement()? {
+        emit!(SettlementFinalized {
+            event: event.key(),
+            event_id: event.event_id,
+            total_paid_out: event.total_paid_out,
+            total_fees: event.total_fees,
+            dust,
+        });
+    }
     
     Ok(())
 }
@@ -34,6 +149,7 @@ pub struct AnnounceWinner<'info> {
         mut,
         constraint = event.authority == authority.key() @ Error::Unauthorized,
         constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+        constraint = event.fixture == Pubkey::default() @ Error::FixtureMarket,
     )]
     pub event: Account<'info, Event>,
 }
@@ -45,3 +161,21 @@ pub struct WinnerAnnounced {
     pub win_a_amount: u128,
     pub win_b_amount: u128,
 }
+
+#[event]
+pub struct BracketAdvanced {
+    pub tournament: Pubkey,
+    pub event: Pubkey,
+    pub next_event: Pubkey,
+    pub slot: u8,
+    pub winner: String,
+}
+
+#[event]
+pub struct TournamentResolved {
+    pub tournament: Pubkey,
+    pub champion: u8,
+    pub winner: String,
+    pub winning_pool: u64,
+    pub total_pool: u64,
+}
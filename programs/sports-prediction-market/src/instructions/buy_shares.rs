@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome, Position};
+use crate::error::Error;
+use crate::math::{self, Rounding};
+use crate::vault::{assert_solvent, transfer_to_vault};
+
+/// Buys `outcome` shares from the AMM pool for `amount` of collateral. The
+/// platform fee comes off the amount first. Fails if fewer than
+/// `min_shares_out` shares would be received.
+pub fn buy_shares(
+    ctx: Context<BuyShares>,
+    outcome: Outcome,
+    amount: u64,
+    min_shares_out: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let position = &mut ctx.accounts.position;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(outcome == Outcome::WinA || outcome == Outcome::WinB, Error::InvalidOutcome);
+    require!(event.market_type == MarketType::Amm, Error::InvalidMarketType);
+    require!(event.betting_open, Error::BettingClosed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
+
+    if position.owner == Pubkey::default() {
+        position.bump = [ctx.bumps.position];
+        position.owner = ctx.accounts.owner.key();
+        position.event = event.key();
+    }
+
+    let fee = math::apply_bps(amount as u128, event.fee_bps, Rounding::Down)
+        .map_err(Error::from)? as u64;
+    let investment = amount - fee;
+    let (side, other) = if outcome == Outcome::WinA {
+        (event.amm_reserve_a, event.amm_reserve_b)
+    } else {
+        (event.amm_reserve_b, event.amm_reserve_a)
+    };
+    let shares = math::cpmm_buy(side, other, investment).map_err(Error::from)?;
+    require!(shares >= min_shares_out, Error::SlippageExceeded);
+
+    // The investment mints complete sets into the pool, which releases `shares`
+    let new_side = side.checked_add(investment)
+        .and_then(|reserve| reserve.checked_sub(shares))
+        .ok_or(Error::OverflowError)?;
+    let new_other = other.checked_add(investment)
+        .ok_or(Error::OverflowError)?;
+    if outcome == Outcome::WinA {
+        (event.amm_reserve_a, event.amm_reserve_b) = (new_side, new_other);
+    } else {
+        (event.amm_reserve_b, event.amm_reserve_a) = (new_side, new_other);
+    }
+    event.amm_collateral = event.amm_collateral.checked_add(investment)
+        .ok_or(Error::OverflowError)?;
+    event.accrued_fees = event.accrued_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+    event.total_fees = event.total_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+
+    let held = position.shares_mut(outcome);
+    *held = held.checked_add(shares)
+        .ok_or(Error::OverflowError)?;
+
+    transfer_to_vault(
+        event,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(SharesTraded {
+        event: event.key(),
+        owner: position.owner,
+        outcome,
+        shares,
+        collateral: amount,
+        fee,
+        is_buy: true,
+        reserve_a: event.amm_reserve_a,
+        reserve_b: event.amm_reserve_b,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BuyShares<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init_if_needed,
+        seeds = [b"position", event.key().as_ref(), owner.key().as_ref()],
+        bump,
+        space = Position::SPACE,
+        payer = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SharesTraded {
+    pub event: Pubkey,
+    pub owner: Pubkey,
+    pub outcome: Outcome,
+    pub shares: u64,
+    /// Collateral paid in (buy) or received (sell), after fees on sells
+    pub collateral: u64,
+    pub fee: u64,
+    pub is_buy: bool,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+}
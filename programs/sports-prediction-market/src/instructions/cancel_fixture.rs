@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::{Fixture, FixtureStatus, Outcome};
+use crate::error::Error;
+use crate::instructions::{load_fixture_markets, record_void};
+
+/// Cancels a fixture that will not be played and voids each of its markets.
+/// Remaining accounts are every market on the fixture (writable); markets
+/// that were already resolved are left as they are.
+pub fn cancel_fixture<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelFixture<'info>>,
+) -> Result<()> {
+    let fixture = &mut ctx.accounts.fixture;
+    let markets = load_fixture_markets(fixture, ctx.remaining_accounts)?;
+
+    fixture.status = FixtureStatus::Cancelled;
+
+    for mut event in markets {
+        if event.outcome != Outcome::Undrawn {
+            continue;
+        }
+
+        record_void(&mut event)?;
+        event.exit(&crate::ID)?;
+    }
+
+    emit!(FixtureCancelled {
+        fixture: fixture.key(),
+        fixture_id: fixture.fixture_id,
+        markets: fixture.market_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelFixture<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = fixture.authority == authority.key() @ Error::Unauthorized,
+        constraint = fixture.status == FixtureStatus::Scheduled @ Error::FixtureNotScheduled,
+    )]
+    pub fixture: Account<'info, Fixture>,
+}
+
+#[event]
+pub struct FixtureCancelled {
+    pub fixture: Pubkey,
+    pub fixture_id: u64,
+    pub markets: u16,
+}
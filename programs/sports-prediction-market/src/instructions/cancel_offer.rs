@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Offer};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Cancels the unmatched remainder of an offer and refunds its escrow.
+/// Matched bets are unaffected.
+pub fn cancel_offer(
+    ctx: Context<CancelOffer>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let offer = &ctx.accounts.offer;
+    let refund = offer.escrow;
+
+    event.exchange_escrow = event.exchange_escrow.checked_sub(refund)
+        .ok_or(Error::InvariantViolation)?;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.maker.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        refund,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(OfferCancelled {
+        offer: offer.key(),
+        event: event.key(),
+        maker: offer.maker,
+        stake_remaining: offer.stake_remaining,
+        refund,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        close = maker,
+        constraint = offer.maker == maker.key() @ Error::Unauthorized,
+        constraint = offer.event == event.key() @ Error::InvalidEvent,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct OfferCancelled {
+    pub offer: Pubkey,
+    pub event: Pubkey,
+    pub maker: Pubkey,
+    pub stake_remaining: u64,
+    pub refund: u64,
+}
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Bet, MarketType, Outcome};
+use crate::error::Error;
+use crate::instructions::calculate_payout;
+use crate::math;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Closes a pari-mutuel bet before the result. The bet is valued at what it
+/// would pay if its side won at the current pools, weighted by the
+/// probability the rest of the pool implies for that side, less the event's
+/// cash-out fee. Whatever the bettor does not get back is booked as a
+/// cash-out fee. Fails if the value is below `min_amount_out`.
+pub fn cash_out(
+    ctx: Context<CashOut>,
+    min_amount_out: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let bet = &ctx.accounts.bet;
+
+    // Validate inputs
+    require!(
+        event.market_type == MarketType::Parimutuel
+            && !event.market_kind.is_scalar()
+            && !event.stake_weighted(),
+        Error::InvalidMarketType
+    );
+    require!(event.betting_open, Error::BettingClosed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
+    require!(!bet.settled, Error::BetSettled);
+
+    let stake = bet.amount;
+    let (side_pool, other_pool) = if bet.outcome == Outcome::WinA {
+        (event.win_a_amount, event.win_b_amount)
+    } else {
+        (event.win_b_amount, event.win_a_amount)
+    };
+
+    let if_won = calculate_payout(event.fee_mode, event.fee_bps, stake, side_pool, other_pool)?;
+    let value = math::cash_out_value(if_won.payout, stake, side_pool, other_pool, event.cash_out_fee_bps)
+        .map_err(Error::from)?;
+    let fee = stake.checked_sub(value)
+        .ok_or(Error::InvariantViolation)?;
+    require!(value >= min_amount_out, Error::SlippageExceeded);
+
+    // Take the whole stake out of the pool so remaining bettors' shares are
+    // computed as if the bet had never been placed, and hand its early-bird
+    // allowance back to later bettors
+    let side_pool = side_pool.checked_sub(stake as u128)
+        .ok_or(Error::InvariantViolation)?;
+    if bet.outcome == Outcome::WinA {
+        event.win_a_amount = side_pool;
+        event.weighted_a = event.weighted_a.checked_sub(bet.weighted_amount as u128)
+            .ok_or(Error::InvariantViolation)?;
+        event.early_bird_a = event.early_bird_a.checked_sub(bet.early_bird_amount)
+            .ok_or(Error::InvariantViolation)?;
+        event.promo_reserved_a = event.promo_reserved_a.checked_sub(bet.early_bird_bonus)
+            .ok_or(Error::InvariantViolation)?;
+        event.win_a_count = event.win_a_count.checked_sub(1)
+            .ok_or(Error::InvariantViolation)?;
+    } else {
+        event.win_b_amount = side_pool;
+        event.weighted_b = event.weighted_b.checked_sub(bet.weighted_amount as u128)
+            .ok_or(Error::InvariantViolation)?;
+        event.early_bird_b = event.early_bird_b.checked_sub(bet.early_bird_amount)
+            .ok_or(Error::InvariantViolation)?;
+        event.promo_reserved_b = event.promo_reserved_b.checked_sub(bet.early_bird_bonus)
+            .ok_or(Error::InvariantViolation)?;
+        event.win_b_count = event.win_b_count.checked_sub(1)
+            .ok_or(Error::InvariantViolation)?;
+    }
+    event.outstanding_stake = event.outstanding_stake.checked_sub(stake as u128)
+        .ok_or(Error::InvariantViolation)?;
+    event.total_cashed_out = event.total_cashed_out.checked_add(stake)
+        .ok_or(Error::OverflowError)?;
+    event.total_paid_out = event.total_paid_out.checked_add(value)
+        .ok_or(Error::OverflowError)?;
+    event.accrued_fees = event.accrued_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+    event.total_fees = event.total_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+
+    // A cash-out may not tip the other side past the imbalance limit
+    let remaining_pool = math::total_pool(side_pool, other_pool).map_err(Error::from)?;
+    if event.max_imbalance_bps > 0
+        && remaining_pool >= event.imbalance_min_pool as u128
+        && other_pool > side_pool
+    {
+        let scaled_other = other_pool.checked_mul(10000)
+            .ok_or(Error::OverflowError)?;
+        let allowed = side_pool.checked_mul(event.max_imbalance_bps as u128)
+            .ok_or(Error::OverflowError)?;
+        require!(scaled_other <= allowed, Error::CashOutImbalance);
+    }
+
+    let payout_destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.authority.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &payout_destination,
+        value,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(BetCashedOut {
+        bet: bet.key(),
+        event: event.key(),
+        authority: bet.authority,
+        outcome: bet.outcome,
+        stake,
+        value,
+        fee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CashOut<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        constraint = bet.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(
+        mut,
+        constraint = bet.event == event.key() @ Error::InvalidEvent,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct BetCashedOut {
+    pub bet: Pubkey,
+    pub event: Pubkey,
+    pub authority: Pubkey,
+    pub outcome: Outcome,
+    pub stake: u64,
+    pub value: u64,
+    pub fee: u64,
+}
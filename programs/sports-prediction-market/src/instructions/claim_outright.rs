@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::state::{OutrightBet, Tournament};
+use crate::error::Error;
+use crate::math;
+
+/// Settles an outright bet once the champion is known. Backers of the
+/// champion split the pool pro rata, with the fee taken off their winnings;
+/// if nobody backed the champion, or the tournament was voided, every stake
+/// is refunded. The bet account closes back to its owner either way.
+pub fn claim_outright(
+    ctx: Context<ClaimOutright>,
+) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let bet = &ctx.accounts.bet;
+
+    let (payout, fee) = if tournament.voided {
+        (bet.amount, 0)
+    } else {
+        let champion = tournament.champion.ok_or(Error::EventNotSettled)?;
+        let winning_pool = tournament.entrant_pools[champion as usize];
+
+        if winning_pool == 0 {
+            (bet.amount, 0)
+        } else if bet.entrant == champion {
+            let losing_pool = tournament.total_pool.checked_sub(winning_pool)
+                .ok_or(Error::InvariantViolation)?;
+            let settlement = math::net_winnings_payout(
+                bet.amount,
+                tournament.fee_bps,
+                winning_pool as u128,
+                losing_pool as u128,
+            ).map_err(Error::from)?;
+            (settlement.payout, settlement.fee)
+        } else {
+            (0, 0)
+        }
+    };
+
+    let tournament_info = tournament.to_account_info();
+    require!(
+        tournament_info.lamports() >= payout.checked_add(fee).ok_or(Error::OverflowError)?,
+        Error::InsufficientFunds
+    );
+    for (destination, amount) in [
+        (ctx.accounts.authority.to_account_info(), payout),
+        (ctx.accounts.platform_fee_account.to_account_info(), fee),
+    ] {
+        **tournament_info.try_borrow_mut_lamports()? -= amount;
+        **destination.try_borrow_mut_lamports()? += amount;
+    }
+
+    emit!(OutrightClaimed {
+        tournament: tournament.key(),
+        authority: bet.authority,
+        entrant: bet.entrant,
+        payout,
+        fee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimOutright<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [b"outright_bet", tournament.key().as_ref(), authority.key().as_ref()],
+        bump = bet.bump[0],
+        close = authority,
+    )]
+    pub bet: Account<'info, OutrightBet>,
+
+    /// CHECK: Treasury wallet recorded on the tournament
+    #[account(
+        mut,
+        constraint = platform_fee_account.key() == tournament.platform_fee_account @ Error::InvalidFeeRecipient,
+    )]
+    pub platform_fee_account: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct OutrightClaimed {
+    pub tournament: Pubkey,
+    pub authority: Pubkey,
+    pub entrant: u8,
+    pub payout: u64,
+    pub fee: u64,
+}
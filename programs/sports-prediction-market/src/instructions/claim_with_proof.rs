@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, ClaimBitmap};
+use crate::error::Error;
+use crate::merkle::{payout_leaf, verify_proof};
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Pays leaf `index` of the event's payout tree to the signing bettor
+pub fn claim_with_proof(
+    ctx: Context<ClaimWithProof>,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let claim_bitmap = &mut ctx.accounts.claim_bitmap;
+    let claimant = ctx.accounts.claimant.key();
+
+    // Validate inputs
+    require!(event.merkle_settlement, Error::InvalidPayoutRoot);
+    require!(index < event.claim_count, Error::InvalidMerkleProof);
+    require!(!claim_bitmap.is_claimed(index), Error::PayoutAlreadyClaimed);
+    require!(
+        verify_proof(&proof, &event.payout_root, payout_leaf(index, &claimant, amount)),
+        Error::InvalidMerkleProof
+    );
+
+    claim_bitmap.set_claimed(index);
+
+    // A tree that pays out more than the posted total cannot drain other claims
+    event.unclaimed_payouts = event.unclaimed_payouts.checked_sub(amount)
+        .ok_or(Error::InvariantViolation)?;
+    event.total_paid_out = event.total_paid_out.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    let payout_destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.claimant.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &payout_destination,
+        amount,
+    )?;
+
+    emit!(PayoutClaimed {
+        event: event.key(),
+        claimant,
+        index,
+        amount,
+    });
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimWithProof<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"claims", event.key().as_ref()],
+        bump = claim_bitmap.bump[0],
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct PayoutClaimed {
+    pub event: Pubkey,
+    pub claimant: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+}
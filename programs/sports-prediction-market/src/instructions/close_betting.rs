@@ -17,6 +17,20 @@ pub fn close_betting(ctx: Context<CloseBetting>) -> Result<()> {
         closed_by: ctx.accounts.authority.key(),
     });
 
+    // Closing a private event opens its reveal window
+    if event.private_betting && event.reveal_ends_at == 0 {
+        event.reveal_ends_at = Clock::get()?.unix_timestamp
+            .checked_add(event.reveal_window)
+            .ok_or(Error::OverflowError)?;
+
+        emit!(RevealWindowOpened {
+            event: event.key(),
+            event_id: event.event_id,
+            reveal_ends_at: event.reveal_ends_at,
+            committed_amount: event.committed_amount,
+        });
+    }
+
     Ok(())
 }
 
@@ -38,3 +52,11 @@ pub struct BettingClosed {
     pub event_id: u64,
     pub closed_by: Pubkey,
 }
+
+#[event]
+pub struct RevealWindowOpened {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub reveal_ends_at: i64,
+    pub committed_amount: u64,
+}
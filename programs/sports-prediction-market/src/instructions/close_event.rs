@@ -1,15 +1,32 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, CloseAccount};
-use crate::state::{Event, Outcome};
+use crate::state::{Event, Fixture, FixtureStatus, Outcome};
 use crate::error::Error;
 
-pub fn close_event(
-    ctx: Context<CloseEvent>,
+/// Closes a settled event and returns its rent to the authority. A fixture
+/// market also takes its fixture as the first remaining account, since the
+/// fixture needs every market live until its score is reported or it is
+/// cancelled.
+pub fn close_event<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseEvent<'info>>,
 ) -> Result<()> {
     let event = &mut ctx.accounts.event;
 
+    if event.fixture != Pubkey::default() {
+        let fixture_info = ctx.remaining_accounts.first().ok_or(Error::FixtureMarket)?;
+        require_keys_eq!(fixture_info.key(), event.fixture, Error::InvalidFixture);
+        let fixture: Account<'info, Fixture> = Account::try_from(fixture_info)?;
+        require!(fixture.status != FixtureStatus::Scheduled, Error::FixtureMarket);
+    }
+
     // Validate that event is settled
     require!(event.outcome != Outcome::Undrawn, Error::EventNotSettled);
+    require!(event.accrued_fees == 0, Error::FeesNotWithdrawn);
+
+    // Closing empties the vault, so nothing may still be owed
+    require!(event.liabilities()? == 0, Error::InsufficientFunds);
+    // Open parlays read the leg's result when they settle
+    require!(event.open_parlay_legs == 0, Error::OpenParlays);
 
     // Close SPL token vault if event uses SPL tokens
     if event.uses_spl_token {
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::{BetCommitment, Event, Outcome};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_to_vault};
+
+/// Escrows a private bet's stake behind `commitment`, which is
+/// `BetCommitment::hash` of the event, the bettor, the chosen outcome and a
+/// secret salt. The stake is visible; the side stays hidden until reveal.
+pub fn commit_bet(
+    ctx: Context<CommitBet>,
+    commitment: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let bet_commitment = &mut ctx.accounts.commitment;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(event.private_betting, Error::InvalidMarketType);
+    require!(event.betting_open, Error::BettingClosed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
+
+    // Enforce per-bet and per-wallet limits on the escrowed stake
+    require!(amount >= event.min_bet, Error::BetBelowMinimum);
+    require!(event.max_bet == 0 || amount <= event.max_bet, Error::BetAboveMaximum);
+    require!(
+        event.max_wallet_exposure == 0 || amount <= event.max_wallet_exposure,
+        Error::WalletExposureExceeded
+    );
+
+    bet_commitment.bump = [ctx.bumps.commitment];
+    bet_commitment.event = event.key();
+    bet_commitment.authority = ctx.accounts.authority.key();
+    bet_commitment.commitment = commitment;
+    bet_commitment.amount = amount;
+
+    event.committed_amount = event.committed_amount.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    event.commit_count = event.commit_count.checked_add(1)
+        .ok_or(Error::OverflowError)?;
+
+    transfer_to_vault(
+        event,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(BetCommitted {
+        commitment: bet_commitment.key(),
+        event: event.key(),
+        authority: bet_commitment.authority,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitBet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        seeds = [b"commitment", event.key().as_ref(), authority.key().as_ref()],
+        bump,
+        space = BetCommitment::SPACE,
+        payer = authority,
+    )]
+    pub commitment: Account<'info, BetCommitment>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BetCommitted {
+    pub commitment: Pubkey,
+    pub event: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
-use crate::state::{Event, Bet, Outcome};
+use crate::state::{Event, Bet, MarketType, Outcome};
 use crate::error::Error;
+use crate::math::{self, Rounding};
+use crate::vault::{assert_solvent, transfer_to_vault};
 
 pub fn create_bet(
     ctx: Context<CreateBet>,
@@ -17,65 +18,197 @@ pub fn create_bet(
 
     // Check if betting is currently open (controlled by admin)
     require!(event.betting_open, Error::BettingClosed);
+    require!(!event.private_betting, Error::PrivateBetting);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
 
     // Check if event is still undrawn
     require!(event.outcome == Outcome::Undrawn, Error::EventSettled);
-    
-    // Initialize bet account
-    bet.bump = [ctx.bumps.bet];
-    bet.authority = ctx.accounts.authority.key();
-    bet.event = event.key();
-    bet.outcome = outcome;
-    bet.amount = amount;
-    bet.settled = false;
-    
+
+    // AMM and conditional-token markets trade shares instead of taking bets
+    require!(
+        matches!(event.market_type, MarketType::Parimutuel | MarketType::FixedOdds),
+        Error::InvalidMarketType
+    );
+
+    // Enforce per-bet limits
+    require!(amount >= event.min_bet, Error::BetBelowMinimum);
+    require!(event.max_bet == 0 || amount <= event.max_bet, Error::BetAboveMaximum);
+
+    // A wallet holds a single position per event; repeat bets top it up
+    let is_new_bet = bet.authority == Pubkey::default();
+    if is_new_bet {
+        bet.bump = [ctx.bumps.bet];
+        bet.authority = ctx.accounts.authority.key();
+        bet.event = event.key();
+        bet.outcome = outcome;
+        bet.amount = 0;
+        bet.settled = false;
+        bet.potential_payout = 0;
+        bet.odds_bps = 0;
+        bet.weighted_amount = 0;
+        bet.early_bird_amount = 0;
+        bet.early_bird_bonus = 0;
+    } else {
+        require!(bet.authority == ctx.accounts.authority.key(), Error::Unauthorized);
+        require!(!bet.settled, Error::BetSettled);
+        require!(bet.outcome == outcome, Error::OutcomeMismatch);
+    }
+
+    // Enforce the per-wallet exposure cap on the total position
+    let exposure = bet.amount.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    require!(
+        event.max_wallet_exposure == 0 || exposure <= event.max_wallet_exposure,
+        Error::WalletExposureExceeded
+    );
+    bet.amount = exposure;
+
+    let weight = event.stake_weight(amount, now)?;
+    bet.weighted_amount = bet.weighted_amount.checked_add(weight)
+        .ok_or(Error::OverflowError)?;
+
+    // Early stakes reserve a bonus from the promo budget while it lasts
+    let (eligible, bonus) = event.early_bird_reservation(outcome, amount, now)?;
+    if outcome == Outcome::WinA {
+        event.early_bird_a = event.early_bird_a.checked_add(eligible)
+            .ok_or(Error::OverflowError)?;
+        event.promo_reserved_a = event.promo_reserved_a.checked_add(bonus)
+            .ok_or(Error::OverflowError)?;
+    } else {
+        event.early_bird_b = event.early_bird_b.checked_add(eligible)
+            .ok_or(Error::OverflowError)?;
+        event.promo_reserved_b = event.promo_reserved_b.checked_add(bonus)
+            .ok_or(Error::OverflowError)?;
+    }
+    bet.early_bird_amount = bet.early_bird_amount.checked_add(eligible)
+        .ok_or(Error::OverflowError)?;
+    bet.early_bird_bonus = bet.early_bird_bonus.checked_add(bonus)
+        .ok_or(Error::OverflowError)?;
+
     // Update event totals
     if outcome == Outcome::WinA {
         event.win_a_amount = event.win_a_amount.checked_add(amount as u128)
             .ok_or(Error::OverflowError)?;
-        event.win_a_count += 1;
+        event.weighted_a = event.weighted_a.checked_add(weight as u128)
+            .ok_or(Error::OverflowError)?;
+        if is_new_bet {
+            event.win_a_count = event.win_a_count.checked_add(1)
+                .ok_or(Error::OverflowError)?;
+        }
     } else {
         event.win_b_amount = event.win_b_amount.checked_add(amount as u128)
             .ok_or(Error::OverflowError)?;
-        event.win_b_count += 1;
+        event.weighted_b = event.weighted_b.checked_add(weight as u128)
+            .ok_or(Error::OverflowError)?;
+        if is_new_bet {
+            event.win_b_count = event.win_b_count.checked_add(1)
+                .ok_or(Error::OverflowError)?;
+        }
+    }
+
+    if event.market_type == MarketType::FixedOdds {
+        // Lock in the current odds and reject bets the book cannot cover
+        let odds_bps = if outcome == Outcome::WinA { event.odds_a_bps } else { event.odds_b_bps };
+        let potential_payout = math::apply_bps(amount as u128, odds_bps, Rounding::Down)
+            .map_err(Error::from)?;
+        let potential_payout = u64::try_from(potential_payout).map_err(|_| Error::OverflowError)?;
+        bet.potential_payout = bet.potential_payout.checked_add(potential_payout)
+            .ok_or(Error::OverflowError)?;
+        bet.odds_bps = odds_bps;
+
+        let liability = if outcome == Outcome::WinA { &mut event.liability_a } else { &mut event.liability_b };
+        *liability = liability.checked_add(potential_payout)
+            .ok_or(Error::OverflowError)?;
+        require!(event.book_covered()?, Error::BankrollExceeded);
+    } else {
+        event.outstanding_stake = event.outstanding_stake.checked_add(amount as u128)
+            .ok_or(Error::OverflowError)?;
     }
 
-    // Transfer funds based on token type
-    if event.uses_spl_token {
-        // Transfer SPL tokens
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.user_token_account.to_account_info(),
-            to: ctx.accounts.event_token_vault.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts
-        );
-        token::transfer(cpi_ctx, amount)?;
+    // Enforce hard pool caps
+    let (side_pool, other_pool) = if outcome == Outcome::WinA {
+        (event.win_a_amount, event.win_b_amount)
     } else {
-        // Transfer SOL funds
-        let transfer_instruction = anchor_lang::system_program::Transfer {
-            from: ctx.accounts.authority.to_account_info(),
-            to: ctx.accounts.event_vault.to_account_info(),
-        };
-
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                transfer_instruction,
-            ),
-            amount,
-        )?;
+        (event.win_b_amount, event.win_a_amount)
+    };
+    let total_pool = side_pool.checked_add(other_pool)
+        .ok_or(Error::OverflowError)?;
+    require!(
+        event.max_side_pool == 0 || side_pool <= event.max_side_pool as u128,
+        Error::SidePoolCapExceeded
+    );
+    require!(
+        event.max_total_pool == 0 || total_pool <= event.max_total_pool as u128,
+        Error::TotalPoolCapExceeded
+    );
+
+    // Report caps that this bet filled, pausing betting once the whole pool is full
+    let mut thresholds_hit = Vec::new();
+    if event.max_side_pool > 0 && side_pool == event.max_side_pool as u128 {
+        thresholds_hit.push(RiskThreshold::SidePoolCap);
     }
-    
+    if event.max_total_pool > 0 && total_pool == event.max_total_pool as u128 {
+        event.betting_open = false;
+        thresholds_hit.push(RiskThreshold::TotalPoolCap);
+    }
+
+    // Circuit breaker: pause when this bet pushes the heavier side past the allowed ratio.
+    // A pool with no stake on the other side yet has no ratio to measure.
+    if event.max_imbalance_bps > 0
+        && total_pool >= event.imbalance_min_pool as u128
+        && other_pool > 0
+        && side_pool > other_pool
+    {
+        let scaled_side = side_pool.checked_mul(10000)
+            .ok_or(Error::OverflowError)?;
+        let allowed = other_pool.checked_mul(event.max_imbalance_bps as u128)
+            .ok_or(Error::OverflowError)?;
+        if scaled_side > allowed {
+            event.betting_open = false;
+            thresholds_hit.push(RiskThreshold::Imbalance);
+        }
+    }
+
+    transfer_to_vault(
+        event,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
     emit!(BetCreated {
         bet: bet.key(),
         event: event.key(),
         authority: bet.authority,
         outcome,
         amount,
+        total_amount: bet.amount,
     });
+
+    for threshold in thresholds_hit {
+        emit!(RiskThresholdHit {
+            event: event.key(),
+            event_id: event.event_id,
+            threshold,
+            win_a_amount: event.win_a_amount,
+            win_b_amount: event.win_b_amount,
+            betting_open: event.betting_open,
+        });
+    }
     
     Ok(())
 }
@@ -92,7 +225,7 @@ pub struct CreateBet<'info> {
     pub event: Account<'info, Event>,
 
     #[account(
-        init,
+        init_if_needed,
         seeds = [b"bet", event.key().as_ref(), authority.key().as_ref()],
         bump,
         space = Bet::SPACE,
@@ -137,4 +270,22 @@ pub struct BetCreated {
     pub authority: Pubkey,
     pub outcome: Outcome,
     pub amount: u64,
+    pub total_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum RiskThreshold {
+    SidePoolCap,
+    TotalPoolCap,
+    Imbalance,
+}
+
+#[event]
+pub struct RiskThresholdHit {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub threshold: RiskThreshold,
+    pub win_a_amount: u128,
+    pub win_b_amount: u128,
+    pub betting_open: bool,
 }
@@ -1,14 +1,19 @@
 use anchor_lang::prelude::*;
-use crate::state::{Event, Outcome};
+use crate::state::{Event, FeeMode, MarketKind, MarketType, Outcome};
 use crate::error::Error;
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_event(
     ctx: Context<CreateEvent>,
     event_id: u64,
     opponent_a: String,
     opponent_b: String,
     fee_bps: u32,
+    fee_mode: FeeMode,
     token_mint: Option<Pubkey>,
+    min_bet: u64,
+    max_bet: u64,
+    max_wallet_exposure: u64,
 ) -> Result<()> {
     let event = &mut ctx.accounts.event;
 
@@ -16,6 +21,10 @@ pub fn create_event(
     require!(fee_bps <= 10000, Error::InvalidFee); // Max 100% fee
     require!(opponent_a.len() <= 32, Error::InvalidStringLength);
     require!(opponent_b.len() <= 32, Error::InvalidStringLength);
+    require!(
+        Event::valid_bet_limits(min_bet, max_bet, max_wallet_exposure),
+        Error::InvalidBetLimits
+    );
 
     // Determine if using SPL token
     let uses_spl_token = token_mint.is_some();
@@ -31,6 +40,65 @@ pub fn create_event(
     event.opponent_a = opponent_a;
     event.opponent_b = opponent_b;
     event.fee_bps = fee_bps;
+    event.fee_mode = fee_mode;
+    event.fee_split = Vec::new();
+    event.accrued_fees = 0;
+    event.total_paid_out = 0;
+    event.total_fees = 0;
+    event.winners_settled = 0;
+    event.settlement_finalized = false;
+    event.outstanding_stake = 0;
+    event.outstanding_losing_share = 0;
+    event.merkle_settlement = false;
+    event.payout_root = [0; 32];
+    event.claim_count = 0;
+    event.unclaimed_payouts = 0;
+    event.market_type = MarketType::Parimutuel;
+    event.odds_a_bps = 0;
+    event.odds_b_bps = 0;
+    event.bankroll = 0;
+    event.liability_a = 0;
+    event.liability_b = 0;
+    event.outstanding_payouts = 0;
+    event.amm_reserve_a = 0;
+    event.amm_reserve_b = 0;
+    event.amm_collateral = 0;
+    event.outcome_mint_a = Pubkey::default();
+    event.outcome_mint_b = Pubkey::default();
+    event.conditional_collateral = 0;
+    event.exchange_escrow = 0;
+    event.offer_count = 0;
+    event.total_cashed_out = 0;
+    event.market_kind = MarketKind::Moneyline;
+    event.fixture = Pubkey::default();
+    event.score_reported = false;
+    event.score_a = 0;
+    event.score_b = 0;
+    event.resolved_value = 0;
+    event.tournament = Pubkey::default();
+    event.next_event = Pubkey::default();
+    event.next_slot = 0;
+    event.betting_closes_at = 0;
+    event.decay_starts_at = 0;
+    event.weight_floor_bps = 0;
+    event.weighted_a = 0;
+    event.weighted_b = 0;
+    event.early_bird_bonus_bps = 0;
+    event.early_bird_cap = 0;
+    event.early_bird_ends_at = 0;
+    event.early_bird_a = 0;
+    event.early_bird_b = 0;
+    event.promo_budget = 0;
+    event.promo_reserved_a = 0;
+    event.promo_reserved_b = 0;
+    event.private_betting = false;
+    event.reveal_window = 0;
+    event.reveal_ends_at = 0;
+    event.forfeit_unrevealed = false;
+    event.committed_amount = 0;
+    event.commit_count = 0;
+    event.cash_out_fee_bps = 0;
+    event.open_parlay_legs = 0;
     event.platform_fee_account = ctx.accounts.platform_fee_account.key();
     event.betting_open = true; // Betting is open by default, admin can close it manually
     event.outcome = Outcome::Undrawn;
@@ -40,6 +108,9 @@ pub fn create_event(
     event.win_b_count = 0;
     event.uses_spl_token = uses_spl_token;
     event.token_mint = mint_pubkey;
+    event.min_bet = min_bet;
+    event.max_bet = max_bet;
+    event.max_wallet_exposure = max_wallet_exposure;
 
     emit!(EventCreated {
         event: event.key(),
@@ -47,8 +118,13 @@ pub fn create_event(
         opponent_a: event.opponent_a.clone(),
         opponent_b: event.opponent_b.clone(),
         betting_open: true,
+        fee_bps,
+        fee_mode,
         uses_spl_token,
         token_mint: mint_pubkey,
+        min_bet,
+        max_bet,
+        max_wallet_exposure,
     });
 
     Ok(())
@@ -69,7 +145,7 @@ pub struct CreateEvent<'info> {
     )]
     pub event: Account<'info, Event>,
 
-    /// CHECK: Platform fee collection account
+    /// CHECK: Treasury wallet that can withdraw accrued fees
     #[account(mut)]
     pub platform_fee_account: UncheckedAccount<'info>,
 
@@ -83,6 +159,11 @@ pub struct EventCreated {
     pub opponent_a: String,
     pub opponent_b: String,
     pub betting_open: bool,
+    pub fee_bps: u32,
+    pub fee_mode: FeeMode,
     pub uses_spl_token: bool,
     pub token_mint: Pubkey,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub max_wallet_exposure: u64,
 }
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{Fixture, FixtureStatus};
+use crate::error::Error;
+
+/// Creates a fixture that groups the markets on a single match
+pub fn create_fixture(
+    ctx: Context<CreateFixture>,
+    fixture_id: u64,
+    team_a: String,
+    team_b: String,
+    league: String,
+    start_time: i64,
+) -> Result<()> {
+    let fixture = &mut ctx.accounts.fixture;
+
+    // Validate inputs
+    require!(team_a.len() <= 32, Error::InvalidStringLength);
+    require!(team_b.len() <= 32, Error::InvalidStringLength);
+    require!(league.len() <= 32, Error::InvalidStringLength);
+
+    fixture.bump = [ctx.bumps.fixture];
+    fixture.authority = ctx.accounts.authority.key();
+    fixture.fixture_id = fixture_id;
+    fixture.team_a = team_a;
+    fixture.team_b = team_b;
+    fixture.league = league;
+    fixture.start_time = start_time;
+    fixture.status = FixtureStatus::Scheduled;
+    fixture.market_count = 0;
+    fixture.score_a = 0;
+    fixture.score_b = 0;
+
+    emit!(FixtureCreated {
+        fixture: fixture.key(),
+        fixture_id,
+        team_a: fixture.team_a.clone(),
+        team_b: fixture.team_b.clone(),
+        league: fixture.league.clone(),
+        start_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(fixture_id: u64)]
+pub struct CreateFixture<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [b"fixture", fixture_id.to_le_bytes().as_ref()],
+        bump,
+        space = Fixture::SPACE,
+        payer = authority,
+    )]
+    pub fixture: Account<'info, Fixture>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct FixtureCreated {
+    pub fixture: Pubkey,
+    pub fixture_id: u64,
+    pub team_a: String,
+    pub team_b: String,
+    pub league: String,
+    pub start_time: i64,
+}
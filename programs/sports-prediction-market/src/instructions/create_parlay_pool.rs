@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::ParlayPool;
+
+/// Creates an empty house pool for parlays, owned by the signer
+pub fn create_parlay_pool(
+    ctx: Context<CreateParlayPool>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    pool.bump = [ctx.bumps.pool];
+    pool.authority = ctx.accounts.authority.key();
+    pool.bankroll = 0;
+    pool.open_stake = 0;
+    pool.liability = 0;
+    pool.parlay_count = 0;
+
+    emit!(ParlayPoolUpdated {
+        pool: pool.key(),
+        bankroll: pool.bankroll,
+        liability: pool.liability,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateParlayPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [b"parlay_pool", authority.key().as_ref()],
+        bump,
+        space = ParlayPool::SPACE,
+        payer = authority,
+    )]
+    pub pool: Account<'info, ParlayPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ParlayPoolUpdated {
+    pub pool: Pubkey,
+    pub bankroll: u64,
+    pub liability: u64,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{Tournament, MAX_TOURNAMENT_ENTRANTS};
+use crate::error::Error;
+
+/// Creates a tournament with its entrants and an empty outright pool
+pub fn create_tournament(
+    ctx: Context<CreateTournament>,
+    tournament_id: u64,
+    name: String,
+    entrants: Vec<String>,
+    fee_bps: u32,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    // Validate inputs
+    require!(fee_bps <= 10000, Error::InvalidFee);
+    require!(name.len() <= 32, Error::InvalidStringLength);
+    require!(
+        (2..=MAX_TOURNAMENT_ENTRANTS).contains(&entrants.len()),
+        Error::InvalidEntrant
+    );
+    for (index, entrant) in entrants.iter().enumerate() {
+        require!(entrant.len() <= 32, Error::InvalidStringLength);
+        require!(!entrants[..index].contains(entrant), Error::InvalidEntrant);
+    }
+
+    tournament.bump = [ctx.bumps.tournament];
+    tournament.authority = ctx.accounts.authority.key();
+    tournament.tournament_id = tournament_id;
+    tournament.name = name;
+    tournament.platform_fee_account = ctx.accounts.platform_fee_account.key();
+    tournament.fee_bps = fee_bps;
+    tournament.final_event = Pubkey::default();
+    tournament.entrant_pools = vec![0; entrants.len()];
+    tournament.entrants = entrants;
+    tournament.total_pool = 0;
+    tournament.champion = None;
+    tournament.voided = false;
+
+    emit!(TournamentCreated {
+        tournament: tournament.key(),
+        tournament_id,
+        name: tournament.name.clone(),
+        entrants: tournament.entrants.clone(),
+        fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u64, name: String, entrants: Vec<String>)]
+pub struct CreateTournament<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [b"tournament", tournament_id.to_le_bytes().as_ref()],
+        bump,
+        space = Tournament::space(entrants.len()),
+        payer = authority,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    /// CHECK: Treasury wallet that receives outright fees
+    pub platform_fee_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct TournamentCreated {
+    pub tournament: Pubkey,
+    pub tournament_id: u64,
+    pub name: String,
+    pub entrants: Vec<String>,
+    pub fee_bps: u32,
+}
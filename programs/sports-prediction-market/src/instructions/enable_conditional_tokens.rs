@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+
+/// Decimals of the outcome tokens for SOL-collateralized events (lamports)
+const SOL_DECIMALS: u8 = 9;
+
+/// Converts an event with no bets into a conditional-token market and creates
+/// the WinA and WinB outcome mints, both controlled by the event PDA.
+/// `decimals` must match the collateral so one token unit is one collateral unit.
+pub fn enable_conditional_tokens(
+    ctx: Context<EnableConditionalTokens>,
+    decimals: u8,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    require!(event.can_change_market_type(MarketType::ConditionalTokens), Error::InvalidMarketType);
+    if event.uses_spl_token {
+        require!(ctx.accounts.token_mint.key() == event.token_mint, Error::InvalidMint);
+        let collateral_mint = Mint::try_deserialize(&mut &ctx.accounts.token_mint.try_borrow_data()?[..])
+            .map_err(|_| Error::InvalidMint)?;
+        require!(decimals == collateral_mint.decimals, Error::InvalidMint);
+    } else {
+        require!(decimals == SOL_DECIMALS, Error::InvalidMint);
+    }
+
+    event.market_type = MarketType::ConditionalTokens;
+    event.outcome_mint_a = ctx.accounts.outcome_mint_a.key();
+    event.outcome_mint_b = ctx.accounts.outcome_mint_b.key();
+
+    emit!(ConditionalTokensEnabled {
+        event: event.key(),
+        event_id: event.event_id,
+        outcome_mint_a: event.outcome_mint_a,
+        outcome_mint_b: event.outcome_mint_b,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(decimals: u8)]
+pub struct EnableConditionalTokens<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"mint_a", event.key().as_ref()],
+        bump,
+        mint::decimals = decimals,
+        mint::authority = event,
+    )]
+    pub outcome_mint_a: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"mint_b", event.key().as_ref()],
+        bump,
+        mint::decimals = decimals,
+        mint::authority = event,
+    )]
+    pub outcome_mint_b: Account<'info, Mint>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_mint: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event]
+pub struct ConditionalTokensEnabled {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub outcome_mint_a: Pubkey,
+    pub outcome_mint_b: Pubkey,
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{BetCommitment, Event, Outcome};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Clears a commitment that was not revealed in time. Anyone may call this
+/// once the reveal window has ended. The stake is refunded to the bettor, or
+/// forfeited to the treasury when the event's policy says so; a voided event
+/// always refunds. The commitment account's rent goes back to the bettor.
+pub fn expire_commitment(
+    ctx: Context<ExpireCommitment>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let commitment = &ctx.accounts.commitment;
+
+    let voided = event.outcome == Outcome::Invalid;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        voided || (event.reveal_ends_at != 0 && now >= event.reveal_ends_at),
+        Error::RevealPending
+    );
+
+    let amount = commitment.amount;
+    event.committed_amount = event.committed_amount.checked_sub(amount)
+        .ok_or(Error::InvariantViolation)?;
+
+    let refunded = voided || !event.forfeit_unrevealed;
+    if refunded {
+        // Refunds may only go to the bettor
+        let destination = if event.uses_spl_token {
+            let destination = ctx.accounts.owner_token_account.to_account_info();
+            let token_account = TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])
+                .map_err(|_| Error::InvalidPayoutDestination)?;
+            require!(token_account.owner == commitment.authority, Error::InvalidPayoutDestination);
+            require!(token_account.mint == event.token_mint, Error::InvalidMint);
+            destination
+        } else {
+            ctx.accounts.owner.to_account_info()
+        };
+        transfer_from_vault(
+            event,
+            &ctx.accounts.event_vault.to_account_info(),
+            &ctx.accounts.event_token_vault.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &destination,
+            amount,
+        )?;
+    } else {
+        // Forfeited stakes stay in the vault until the treasury withdraws them
+        event.accrued_fees = event.accrued_fees.checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(CommitmentExpired {
+        commitment: commitment.key(),
+        event: event.key(),
+        authority: commitment.authority,
+        amount,
+        refunded,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireCommitment<'info> {
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        constraint = commitment.event == event.key() @ Error::InvalidEvent,
+        close = owner,
+    )]
+    pub commitment: Account<'info, BetCommitment>,
+
+    /// CHECK: The bettor who made the commitment, constrained below
+    #[account(
+        mut,
+        constraint = owner.key() == commitment.authority @ Error::InvalidPayoutDestination,
+    )]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub owner_token_account: AccountInfo<'info>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct CommitmentExpired {
+    pub commitment: Pubkey,
+    pub event: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+}
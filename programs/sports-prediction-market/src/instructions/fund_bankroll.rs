@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_to_vault};
+
+/// Deposits house liquidity that backs a fixed-odds book
+pub fn fund_bankroll(
+    ctx: Context<FundBankroll>,
+    amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(event.market_type == MarketType::FixedOdds, Error::InvalidMarketType);
+
+    event.bankroll = event.bankroll.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    transfer_to_vault(
+        event,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.authority_token_account.to_account_info(),
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(BankrollUpdated {
+        event: event.key(),
+        event_id: event.event_id,
+        bankroll: event.bankroll,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundBankroll<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub authority_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BankrollUpdated {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub bankroll: u64,
+}
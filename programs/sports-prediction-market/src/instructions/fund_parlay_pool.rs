@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::ParlayPool;
+use crate::error::Error;
+use crate::instructions::ParlayPoolUpdated;
+use crate::vault::assert_pool_solvent;
+
+/// Deposits house liquidity that backs parlay payouts
+pub fn fund_parlay_pool(
+    ctx: Context<FundParlayPool>,
+    amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+
+    pool.bankroll = pool.bankroll.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    let transfer_instruction = anchor_lang::system_program::Transfer {
+        from: ctx.accounts.authority.to_account_info(),
+        to: pool.to_account_info(),
+    };
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_instruction,
+        ),
+        amount,
+    )?;
+
+    assert_pool_solvent(pool)?;
+
+    emit!(ParlayPoolUpdated {
+        pool: pool.key(),
+        bankroll: pool.bankroll,
+        liability: pool.liability,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundParlayPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub pool: Account<'info, ParlayPool>,
+
+    pub system_program: Program<'info, System>,
+}
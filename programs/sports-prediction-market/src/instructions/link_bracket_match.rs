@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Outcome, Tournament};
+use crate::error::Error;
+
+/// Links a bracket match to the match its winner advances to. When the
+/// winner is announced, their name fills opponent slot `slot` (0 = A, 1 = B)
+/// of `next_event`. Fixture markets cannot be bracket matches, since a
+/// reported score does not advance the bracket.
+pub fn link_bracket_match(
+    ctx: Context<LinkBracketMatch>,
+    slot: u8,
+) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let event = &mut ctx.accounts.event;
+    let next_event = &mut ctx.accounts.next_event;
+
+    require!(slot <= 1, Error::InvalidBracket);
+    require!(event.key() != next_event.key(), Error::InvalidBracket);
+    require!(event.next_event == Pubkey::default(), Error::InvalidBracket);
+    require!(event.key() != tournament.final_event, Error::InvalidBracket);
+    for linked in [&**event, &**next_event] {
+        require!(
+            linked.tournament == Pubkey::default() || linked.tournament == tournament.key(),
+            Error::InvalidBracket
+        );
+        require!(linked.fixture == Pubkey::default(), Error::InvalidBracket);
+    }
+
+    event.tournament = tournament.key();
+    event.next_event = next_event.key();
+    event.next_slot = slot;
+    next_event.tournament = tournament.key();
+
+    emit!(BracketMatchLinked {
+        tournament: tournament.key(),
+        event: event.key(),
+        next_event: next_event.key(),
+        slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LinkBracketMatch<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = tournament.authority == authority.key() @ Error::Unauthorized,
+        constraint = !tournament.voided @ Error::EventSettled,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        constraint = next_event.authority == authority.key() @ Error::Unauthorized,
+        constraint = next_event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub next_event: Account<'info, Event>,
+}
+
+#[event]
+pub struct BracketMatchLinked {
+    pub tournament: Pubkey,
+    pub event: Pubkey,
+    pub next_event: Pubkey,
+    pub slot: u8,
+}
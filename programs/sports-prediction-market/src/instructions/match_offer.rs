@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, MatchedBet, Offer, OfferSide, Outcome};
+use crate::error::Error;
+use crate::math;
+use crate::vault::{assert_solvent, transfer_to_vault};
+
+/// Takes the opposite side of `offer` for `stake` of backer stake at the
+/// offer's odds. Both sides' escrow moves into a new matched bet; the rest of
+/// the offer stays open.
+pub fn match_offer(
+    ctx: Context<MatchOffer>,
+    stake: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let offer = &mut ctx.accounts.offer;
+    let matched_bet = &mut ctx.accounts.matched_bet;
+    let taker = ctx.accounts.taker.key();
+
+    // Validate inputs
+    require!(stake > 0, Error::ZeroAmount);
+    require!(event.market_type == MarketType::Exchange, Error::InvalidMarketType);
+    require!(event.betting_open, Error::BettingClosed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
+    require!(taker != offer.maker, Error::SelfMatch);
+    require!(stake <= offer.stake_remaining, Error::OfferExhausted);
+
+    let liability = math::lay_liability(stake, offer.odds_bps).map_err(Error::from)?;
+    require!(liability > 0, Error::ZeroAmount);
+
+    // The maker's share comes out of the offer's escrow; the taker deposits theirs
+    let (backer, layer, from_offer, deposit) = match offer.side {
+        OfferSide::Back => (offer.maker, taker, stake, liability),
+        OfferSide::Lay => (taker, offer.maker, liability, stake),
+    };
+
+    offer.stake_remaining = offer.stake_remaining.checked_sub(stake)
+        .ok_or(Error::InvariantViolation)?;
+    offer.escrow = offer.escrow.checked_sub(from_offer)
+        .ok_or(Error::InvariantViolation)?;
+    offer.fill_count = offer.fill_count.checked_add(1)
+        .ok_or(Error::OverflowError)?;
+
+    matched_bet.bump = [ctx.bumps.matched_bet];
+    matched_bet.event = event.key();
+    matched_bet.backer = backer;
+    matched_bet.layer = layer;
+    matched_bet.taker = taker;
+    matched_bet.outcome = offer.outcome;
+    matched_bet.odds_bps = offer.odds_bps;
+    matched_bet.back_stake = stake;
+    matched_bet.lay_liability = liability;
+
+    event.exchange_escrow = event.exchange_escrow.checked_add(deposit)
+        .ok_or(Error::OverflowError)?;
+
+    transfer_to_vault(
+        event,
+        &ctx.accounts.taker.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        deposit,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(OfferMatched {
+        offer: offer.key(),
+        matched_bet: matched_bet.key(),
+        event: event.key(),
+        backer,
+        layer,
+        outcome: offer.outcome,
+        odds_bps: offer.odds_bps,
+        back_stake: stake,
+        lay_liability: liability,
+        stake_remaining: offer.stake_remaining,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MatchOffer<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        constraint = offer.event == event.key() @ Error::InvalidEvent,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(
+        init,
+        seeds = [b"matched", offer.key().as_ref(), &offer.fill_count.to_le_bytes()],
+        bump,
+        space = MatchedBet::SPACE,
+        payer = taker,
+    )]
+    pub matched_bet: Account<'info, MatchedBet>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct OfferMatched {
+    pub offer: Pubkey,
+    pub matched_bet: Pubkey,
+    pub event: Pubkey,
+    pub backer: Pubkey,
+    pub layer: Pubkey,
+    pub outcome: Outcome,
+    pub odds_bps: u32,
+    pub back_stake: u64,
+    pub lay_liability: u64,
+    pub stake_remaining: u64,
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::{Event, MarketType};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Burns `amount` of both outcome tokens and returns `amount` of collateral
+pub fn merge_positions(
+    ctx: Context<MergePositions>,
+    amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(event.market_type == MarketType::ConditionalTokens, Error::InvalidMarketType);
+
+    for (mint, source) in [
+        (&ctx.accounts.outcome_mint_a, &ctx.accounts.user_outcome_a),
+        (&ctx.accounts.outcome_mint_b, &ctx.accounts.user_outcome_b),
+    ] {
+        let cpi_accounts = Burn {
+            mint: mint.to_account_info(),
+            from: source.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        token::burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+    }
+
+    event.conditional_collateral = event.conditional_collateral.checked_sub(amount)
+        .ok_or(Error::InvariantViolation)?;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.user.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(PositionsMerged {
+        event: event.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MergePositions<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(mut, address = event.outcome_mint_a @ Error::InvalidMint)]
+    pub outcome_mint_a: Account<'info, Mint>,
+
+    #[account(mut, address = event.outcome_mint_b @ Error::InvalidMint)]
+    pub outcome_mint_b: Account<'info, Mint>,
+
+    #[account(mut, token::mint = outcome_mint_a, token::authority = user)]
+    pub user_outcome_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = outcome_mint_b, token::authority = user)]
+    pub user_outcome_b: Account<'info, TokenAccount>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL collateral accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct PositionsMerged {
+    pub event: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
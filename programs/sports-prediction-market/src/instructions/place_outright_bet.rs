@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Outcome, OutrightBet, Tournament};
+use crate::error::Error;
+
+/// Stakes SOL on `entrant` winning the tournament. A wallet holds a single
+/// outright position per tournament; repeat bets top it up. Outright betting
+/// closes with betting on the final.
+pub fn place_outright_bet(
+    ctx: Context<PlaceOutrightBet>,
+    entrant: u8,
+    amount: u64,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let bet = &mut ctx.accounts.bet;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!((entrant as usize) < tournament.entrants.len(), Error::InvalidEntrant);
+
+    let final_event = &ctx.accounts.final_event;
+    require!(final_event.betting_open, Error::BettingClosed);
+    require!(
+        final_event.betting_closes_at == 0
+            || Clock::get()?.unix_timestamp < final_event.betting_closes_at,
+        Error::BettingClosed
+    );
+
+    if bet.authority == Pubkey::default() {
+        bet.bump = [ctx.bumps.bet];
+        bet.tournament = tournament.key();
+        bet.authority = ctx.accounts.authority.key();
+        bet.entrant = entrant;
+        bet.amount = 0;
+    } else {
+        require!(bet.entrant == entrant, Error::OutcomeMismatch);
+    }
+
+    bet.amount = bet.amount.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    let pool = &mut tournament.entrant_pools[entrant as usize];
+    *pool = pool.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    tournament.total_pool = tournament.total_pool.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    let transfer_instruction = anchor_lang::system_program::Transfer {
+        from: ctx.accounts.authority.to_account_info(),
+        to: tournament.to_account_info(),
+    };
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_instruction,
+        ),
+        amount,
+    )?;
+
+    emit!(OutrightBetPlaced {
+        tournament: tournament.key(),
+        authority: bet.authority,
+        entrant,
+        amount,
+        total_amount: bet.amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PlaceOutrightBet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = tournament.champion.is_none() @ Error::EventSettled,
+        constraint = !tournament.voided @ Error::EventSettled,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        constraint = final_event.key() == tournament.final_event @ Error::InvalidBracket,
+        constraint = final_event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub final_event: Account<'info, Event>,
+
+    #[account(
+        init_if_needed,
+        seeds = [b"outright_bet", tournament.key().as_ref(), authority.key().as_ref()],
+        bump,
+        space = OutrightBet::SPACE,
+        payer = authority,
+    )]
+    pub bet: Account<'info, OutrightBet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct OutrightBetPlaced {
+    pub tournament: Pubkey,
+    pub authority: Pubkey,
+    pub entrant: u8,
+    pub amount: u64,
+    pub total_amount: u64,
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome, Parlay, ParlayLeg, ParlayPool, MAX_PARLAY_LEGS};
+use crate::error::Error;
+use crate::math::{self, Rounding};
+use crate::vault::assert_pool_solvent;
+
+/// Places a parlay on `outcomes`, one pick per leg event. Remaining accounts
+/// are the writable leg events, in the same order as `outcomes`. Each leg must
+/// be run by the pool's authority and locks in the event's posted fixed odds;
+/// the parlay pays `stake` times the product of the leg odds if every leg wins.
+pub fn place_parlay<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlaceParlay<'info>>,
+    outcomes: Vec<Outcome>,
+    stake: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let parlay = &mut ctx.accounts.parlay;
+
+    // Validate inputs
+    require!(stake > 0, Error::ZeroAmount);
+    require!(
+        (2..=MAX_PARLAY_LEGS).contains(&outcomes.len()) && ctx.remaining_accounts.len() == outcomes.len(),
+        Error::InvalidParlay
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut legs = Vec::with_capacity(outcomes.len());
+    for (event_info, outcome) in ctx.remaining_accounts.iter().zip(outcomes) {
+        let mut event: Account<'info, Event> = Account::try_from(event_info)?;
+        // Whoever resolves a leg decides whether the pool pays out
+        require!(event.authority == pool.authority, Error::Unauthorized);
+        require!(outcome == Outcome::WinA || outcome == Outcome::WinB, Error::InvalidOutcome);
+        require!(event.outcome == Outcome::Undrawn, Error::EventSettled);
+        require!(event.betting_open, Error::BettingClosed);
+        require!(
+            event.betting_closes_at == 0 || now < event.betting_closes_at,
+            Error::BettingClosed
+        );
+        require!(
+            legs.iter().all(|leg: &ParlayLeg| leg.event != event.key()),
+            Error::InvalidParlay
+        );
+
+        legs.push(ParlayLeg {
+            event: event.key(),
+            outcome,
+            odds_bps: leg_odds_bps(&event, outcome)?,
+        });
+
+        event.open_parlay_legs = event.open_parlay_legs.checked_add(1)
+            .ok_or(Error::OverflowError)?;
+        event.exit(&crate::ID)?;
+    }
+
+    let combined_odds_bps = ParlayLeg::combined_odds_bps(legs.iter().map(|leg| leg.odds_bps))?;
+    let potential_payout = math::mul_div(stake as u128, combined_odds_bps, math::BPS_DENOMINATOR, Rounding::Down)
+        .map_err(Error::from)?;
+    let potential_payout = u64::try_from(potential_payout).map_err(|_| Error::OverflowError)?;
+
+    pool.open_stake = pool.open_stake.checked_add(stake)
+        .ok_or(Error::OverflowError)?;
+    pool.liability = pool.liability.checked_add(potential_payout)
+        .ok_or(Error::OverflowError)?;
+    require!(pool.covered(), Error::BankrollExceeded);
+
+    parlay.bump = [ctx.bumps.parlay];
+    parlay.pool = pool.key();
+    parlay.owner = ctx.accounts.owner.key();
+    parlay.parlay_id = pool.parlay_count;
+    parlay.stake = stake;
+    parlay.potential_payout = potential_payout;
+    parlay.legs = legs;
+
+    pool.parlay_count = pool.parlay_count.checked_add(1)
+        .ok_or(Error::OverflowError)?;
+
+    let transfer_instruction = anchor_lang::system_program::Transfer {
+        from: ctx.accounts.owner.to_account_info(),
+        to: pool.to_account_info(),
+    };
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_instruction,
+        ),
+        stake,
+    )?;
+
+    assert_pool_solvent(pool)?;
+
+    emit!(ParlayPlaced {
+        parlay: parlay.key(),
+        pool: pool.key(),
+        owner: parlay.owner,
+        stake,
+        leg_count: parlay.legs.len() as u8,
+        combined_odds_bps: u64::try_from(combined_odds_bps).map_err(|_| Error::OverflowError)?,
+        potential_payout,
+    });
+
+    Ok(())
+}
+
+/// Decimal odds a parlay leg locks in. Only fixed-odds books qualify:
+/// pari-mutuel pools can be skewed by a large bet just before the parlay is
+/// placed and the bet cashed out afterwards.
+fn leg_odds_bps(event: &Event, outcome: Outcome) -> Result<u32> {
+    require!(event.market_type == MarketType::FixedOdds, Error::InvalidMarketType);
+    let odds_bps = if outcome == Outcome::WinA { event.odds_a_bps } else { event.odds_b_bps };
+    // Odds are unset until the book is opened
+    require!(odds_bps > 10000, Error::InvalidOdds);
+    Ok(odds_bps)
+}
+
+#[derive(Accounts)]
+#[instruction(outcomes: Vec<Outcome>)]
+pub struct PlaceParlay<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub pool: Account<'info, ParlayPool>,
+
+    #[account(
+        init,
+        seeds = [b"parlay", pool.key().as_ref(), &pool.parlay_count.to_le_bytes()],
+        bump,
+        space = Parlay::space(outcomes.len()),
+        payer = owner,
+    )]
+    pub parlay: Account<'info, Parlay>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ParlayPlaced {
+    pub parlay: Pubkey,
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub stake: u64,
+    pub leg_count: u8,
+    pub combined_odds_bps: u64,
+    pub potential_payout: u64,
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Offer, OfferSide, Outcome};
+use crate::error::Error;
+use crate::math;
+use crate::vault::{assert_solvent, transfer_to_vault};
+
+/// Posts a back or lay offer on `outcome` at decimal odds `odds_bps` for up to
+/// `stake` of backer stake. A back offer escrows the stake; a lay offer escrows
+/// the backer's winnings. Posting on a pari-mutuel event with no bets yet
+/// converts the event to an exchange market.
+pub fn post_offer(
+    ctx: Context<PostOffer>,
+    outcome: Outcome,
+    side: OfferSide,
+    odds_bps: u32,
+    stake: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let offer = &mut ctx.accounts.offer;
+
+    // Validate inputs
+    require!(stake > 0, Error::ZeroAmount);
+    require!(outcome == Outcome::WinA || outcome == Outcome::WinB, Error::InvalidOutcome);
+    require!(odds_bps > 10000, Error::InvalidOdds);
+    require!(event.betting_open, Error::BettingClosed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
+
+    if event.market_type == MarketType::Parimutuel {
+        require!(event.can_change_market_type(MarketType::Exchange), Error::InvalidMarketType);
+        event.market_type = MarketType::Exchange;
+    }
+    require!(event.market_type == MarketType::Exchange, Error::InvalidMarketType);
+
+    let escrow = match side {
+        OfferSide::Back => stake,
+        OfferSide::Lay => math::lay_liability(stake, odds_bps).map_err(Error::from)?,
+    };
+    require!(escrow > 0, Error::ZeroAmount);
+
+    offer.bump = [ctx.bumps.offer];
+    offer.event = event.key();
+    offer.maker = ctx.accounts.maker.key();
+    offer.offer_id = event.offer_count;
+    offer.outcome = outcome;
+    offer.side = side;
+    offer.odds_bps = odds_bps;
+    offer.stake_remaining = stake;
+    offer.escrow = escrow;
+    offer.fill_count = 0;
+
+    event.offer_count = event.offer_count.checked_add(1)
+        .ok_or(Error::OverflowError)?;
+    event.exchange_escrow = event.exchange_escrow.checked_add(escrow)
+        .ok_or(Error::OverflowError)?;
+
+    transfer_to_vault(
+        event,
+        &ctx.accounts.maker.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        escrow,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(OfferPosted {
+        offer: offer.key(),
+        event: event.key(),
+        maker: offer.maker,
+        outcome,
+        side,
+        odds_bps,
+        stake,
+        escrow,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PostOffer<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        seeds = [b"offer", event.key().as_ref(), &event.offer_count.to_le_bytes()],
+        bump,
+        space = Offer::SPACE,
+        payer = maker,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct OfferPosted {
+    pub offer: Pubkey,
+    pub event: Pubkey,
+    pub maker: Pubkey,
+    pub outcome: Outcome,
+    pub side: OfferSide,
+    pub odds_bps: u32,
+    pub stake: u64,
+    pub escrow: u64,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, ClaimBitmap, MarketType, Outcome, MAX_MERKLE_CLAIMS};
+use crate::error::Error;
+use crate::math;
+
+/// Switches a resolved event to merkle settlement. The resolver computes every
+/// winner's payout off-chain (see `merkle::PayoutTree`) and posts the root and
+/// total; whatever the tree does not pay out is booked as fees and dust.
+pub fn post_payout_root(
+    ctx: Context<PostPayoutRoot>,
+    payout_root: [u8; 32],
+    claim_count: u32,
+    total_payout: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(payout_root != [0; 32], Error::InvalidPayoutRoot);
+    require!(claim_count > 0 && claim_count <= MAX_MERKLE_CLAIMS, Error::InvalidPayoutRoot);
+
+    require!(event.market_type == MarketType::Parimutuel, Error::InvalidMarketType);
+    require!(event.promo_owed()? == 0, Error::PromoActive);
+
+    // Per-bet and merkle settlement cannot be mixed
+    require!(
+        event.winners_settled == 0 && !event.settlement_finalized,
+        Error::MerkleSettlementActive
+    );
+
+    let total_pool = math::total_pool(event.win_a_amount, event.win_b_amount).map_err(Error::from)?;
+    let retained = total_pool.checked_sub(total_payout as u128)
+        .ok_or(Error::InvalidPayoutRoot)?;
+    let retained = u64::try_from(retained).map_err(|_| Error::OverflowError)?;
+
+    event.merkle_settlement = true;
+    event.payout_root = payout_root;
+    event.claim_count = claim_count;
+    event.unclaimed_payouts = total_payout;
+
+    // The tree replaces per-bet accounting; the rest of the pool goes to the treasury
+    event.total_fees = event.total_fees.checked_add(retained)
+        .ok_or(Error::OverflowError)?;
+    event.accrued_fees = event.accrued_fees.checked_add(retained)
+        .ok_or(Error::OverflowError)?;
+    event.outstanding_stake = 0;
+    event.outstanding_losing_share = 0;
+    event.settlement_finalized = true;
+
+    let claim_bitmap = &mut ctx.accounts.claim_bitmap;
+    claim_bitmap.bump = [ctx.bumps.claim_bitmap];
+    claim_bitmap.event = event.key();
+    claim_bitmap.bits = vec![0; ClaimBitmap::byte_len(claim_count)];
+
+    emit!(PayoutRootPosted {
+        event: event.key(),
+        event_id: event.event_id,
+        payout_root,
+        claim_count,
+        total_payout,
+        fees: retained,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(payout_root: [u8; 32], claim_count: u32)]
+pub struct PostPayoutRoot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome != Outcome::Undrawn @ Error::EventNotSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ClaimBitmap::space(claim_count),
+        seeds = [b"claims", event.key().as_ref()],
+        bump
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PayoutRootPosted {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub payout_root: [u8; 32],
+    pub claim_count: u32,
+    pub total_payout: u64,
+    pub fees: u64,
+}
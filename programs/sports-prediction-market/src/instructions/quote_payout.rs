@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Bet, MarketKind, MarketType, Outcome};
+use crate::error::Error;
+use crate::instructions::{bet_settlement, early_bird_bonus};
+use crate::math::{self, Rounding};
+
+/// Projects the settlement of a new `amount` on `outcome` at the current pool
+/// sizes, assuming that outcome wins. The bet is priced the way `settle_bet`
+/// would pay it, including its stake weight and any early-bird bonus it would
+/// reserve now. Returned through `set_return_data`, so clients can call it
+/// with a simulated transaction.
+pub fn quote_payout(
+    ctx: Context<QuotePayout>,
+    outcome: Outcome,
+    amount: u64,
+) -> Result<PayoutQuote> {
+    let event = &ctx.accounts.event;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(outcome == Outcome::WinA || outcome == Outcome::WinB, Error::InvalidOutcome);
+
+    if event.market_type == MarketType::FixedOdds {
+        let odds_bps = if outcome == Outcome::WinA { event.odds_a_bps } else { event.odds_b_bps };
+        return fixed_odds_quote(amount, odds_bps);
+    }
+    if event.market_type == MarketType::Amm {
+        return amm_quote(event, outcome, amount);
+    }
+    require!(event.market_type == MarketType::Parimutuel, Error::InvalidMarketType);
+
+    // Place the bet on a copy of the event, as create_bet would
+    let now = Clock::get()?.unix_timestamp;
+    let weighted_amount = event.stake_weight(amount, now)?;
+    let (early_bird_amount, early_bird_bonus) = event.early_bird_reservation(outcome, amount, now)?;
+    let bet = Bet {
+        bump: [0],
+        authority: Pubkey::default(),
+        event: event.key(),
+        outcome,
+        amount,
+        settled: false,
+        potential_payout: 0,
+        odds_bps: 0,
+        weighted_amount,
+        early_bird_amount,
+        early_bird_bonus,
+    };
+
+    let mut placed: Event = (**event).clone();
+    if outcome == Outcome::WinA {
+        placed.win_a_amount = placed.win_a_amount.checked_add(amount as u128)
+            .ok_or(Error::OverflowError)?;
+        placed.weighted_a = placed.weighted_a.checked_add(weighted_amount as u128)
+            .ok_or(Error::OverflowError)?;
+    } else {
+        placed.win_b_amount = placed.win_b_amount.checked_add(amount as u128)
+            .ok_or(Error::OverflowError)?;
+        placed.weighted_b = placed.weighted_b.checked_add(weighted_amount as u128)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    projected_win(&placed, &bet)
+}
+
+/// Quotes `bet` as if its side won outright. A scalar market is resolved at
+/// the end of its range that pays that side the whole pool.
+pub fn projected_win(event: &Event, bet: &Bet) -> Result<PayoutQuote> {
+    let mut resolved = event.clone();
+    resolved.outcome = bet.outcome;
+    if let MarketKind::Scalar { min, max } = resolved.market_kind {
+        resolved.resolved_value = if bet.outcome == Outcome::WinA { max } else { min };
+    }
+    settled_quote(&resolved, bet)
+}
+
+/// Quotes what `settle_bet` pays `bet` on the resolved `event`, bonus included
+pub fn settled_quote(event: &Event, bet: &Bet) -> Result<PayoutQuote> {
+    let settlement = bet_settlement(event, bet)?;
+    let payout = settlement.payout.checked_add(early_bird_bonus(event, bet))
+        .ok_or(Error::OverflowError)?;
+
+    let implied_odds_bps = if event.market_type == MarketType::FixedOdds {
+        bet.odds_bps as u64
+    } else {
+        let (side_pool, other_pool) = if bet.outcome == Outcome::WinA {
+            (event.win_a_amount, event.win_b_amount)
+        } else {
+            (event.win_b_amount, event.win_a_amount)
+        };
+        math::implied_odds_bps(side_pool, other_pool).map_err(Error::from)?
+    };
+
+    Ok(PayoutQuote {
+        fee: settlement.fee,
+        net_stake: settlement.net_stake,
+        payout,
+        implied_odds_bps,
+    })
+}
+
+/// Quotes `stake` at fixed decimal odds; no platform fee applies
+pub fn fixed_odds_quote(stake: u64, odds_bps: u32) -> Result<PayoutQuote> {
+    let payout = math::apply_bps(stake as u128, odds_bps, Rounding::Down).map_err(Error::from)?;
+    Ok(PayoutQuote {
+        fee: 0,
+        net_stake: stake,
+        payout: u64::try_from(payout).map_err(|_| Error::OverflowError)?,
+        implied_odds_bps: odds_bps as u64,
+    })
+}
+
+/// Quotes buying `outcome` shares for `amount`; each share pays one unit on a win
+fn amm_quote(event: &Event, outcome: Outcome, amount: u64) -> Result<PayoutQuote> {
+    let fee = math::apply_bps(amount as u128, event.fee_bps, Rounding::Down)
+        .map_err(Error::from)? as u64;
+    let investment = amount - fee;
+    let (side, other) = if outcome == Outcome::WinA {
+        (event.amm_reserve_a, event.amm_reserve_b)
+    } else {
+        (event.amm_reserve_b, event.amm_reserve_a)
+    };
+    let shares = math::cpmm_buy(side, other, investment).map_err(Error::from)?;
+    let implied_odds_bps = math::mul_div(shares as u128, math::BPS_DENOMINATOR, amount as u128, Rounding::Down)
+        .map_err(Error::from)?;
+
+    Ok(PayoutQuote {
+        fee,
+        net_stake: investment,
+        payout: shares,
+        implied_odds_bps: u64::try_from(implied_odds_bps).map_err(|_| Error::OverflowError)?,
+    })
+}
+
+#[derive(Accounts)]
+pub struct QuotePayout<'info> {
+    pub event: Account<'info, Event>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct PayoutQuote {
+    /// Platform fee charged on a win
+    pub fee: u64,
+    /// Stake left after fees charged on the stake itself
+    pub net_stake: u64,
+    /// Total returned to the bettor on a win
+    pub payout: u64,
+    /// Decimal odds implied by the pools, in basis points (25000 = 2.5)
+    pub implied_odds_bps: u64,
+}
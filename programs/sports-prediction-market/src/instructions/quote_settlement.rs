@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Bet, Outcome};
+use crate::error::Error;
+use crate::instructions::{projected_win, settled_quote, PayoutQuote};
+
+/// Quotes what `settle_bet` pays for an existing bet. Before the winner is
+/// announced this projects a win at the current pool sizes; afterwards it
+/// returns the actual settlement (zero payout for a losing bet, the stake for
+/// a void event).
+pub fn quote_settlement(ctx: Context<QuoteSettlement>) -> Result<PayoutQuote> {
+    let event = &ctx.accounts.event;
+    let bet = &ctx.accounts.bet;
+
+    if event.outcome == Outcome::Undrawn {
+        projected_win(event, bet)
+    } else {
+        settled_quote(event, bet)
+    }
+}
+
+#[derive(Accounts)]
+pub struct QuoteSettlement<'info> {
+    pub event: Account<'info, Event>,
+
+    #[account(
+        constraint = bet.event == event.key() @ Error::InvalidEvent,
+    )]
+    pub bet: Account<'info, Bet>,
+}
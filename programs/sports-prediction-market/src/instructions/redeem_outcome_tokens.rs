@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Burns `amount` of the winning outcome token and pays `amount` of collateral
+pub fn redeem_outcome_tokens(
+    ctx: Context<RedeemOutcomeTokens>,
+    amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(event.market_type == MarketType::ConditionalTokens, Error::InvalidMarketType);
+    require!(event.outcome != Outcome::Undrawn, Error::EventNotSettled);
+
+    let winning_mint = if event.outcome == Outcome::WinA {
+        event.outcome_mint_a
+    } else {
+        event.outcome_mint_b
+    };
+    require!(ctx.accounts.winning_mint.key() == winning_mint, Error::InvalidMint);
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.winning_mint.to_account_info(),
+        from: ctx.accounts.user_outcome_tokens.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    token::burn(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+
+    event.conditional_collateral = event.conditional_collateral.checked_sub(amount)
+        .ok_or(Error::InvariantViolation)?;
+    event.total_paid_out = event.total_paid_out.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.user.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(OutcomeTokensRedeemed {
+        event: event.key(),
+        user: ctx.accounts.user.key(),
+        outcome: event.outcome,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemOutcomeTokens<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(mut)]
+    pub winning_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = winning_mint, token::authority = user)]
+    pub user_outcome_tokens: Account<'info, TokenAccount>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL collateral accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct OutcomeTokensRedeemed {
+    pub event: Pubkey,
+    pub user: Pubkey,
+    pub outcome: Outcome,
+    pub amount: u64,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome, Position};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Redeems winning AMM shares 1:1 for collateral and closes the position
+pub fn redeem_shares(
+    ctx: Context<RedeemShares>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let position = &ctx.accounts.position;
+
+    require!(event.market_type == MarketType::Amm, Error::InvalidMarketType);
+    require!(event.outcome != Outcome::Undrawn, Error::EventNotSettled);
+
+    let amount = if event.outcome == Outcome::WinA { position.shares_a } else { position.shares_b };
+    event.amm_collateral = event.amm_collateral.checked_sub(amount)
+        .ok_or(Error::InvariantViolation)?;
+    event.total_paid_out = event.total_paid_out.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.owner.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(SharesRedeemed {
+        event: event.key(),
+        owner: position.owner,
+        outcome: event.outcome,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemShares<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"position", event.key().as_ref(), owner.key().as_ref()],
+        bump = position.bump[0],
+        close = owner,
+    )]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct SharesRedeemed {
+    pub event: Pubkey,
+    pub owner: Pubkey,
+    pub outcome: Outcome,
+    pub amount: u64,
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{Event, MarketType, MatchedBet, Outcome};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Unwinds a matched bet on a voided exchange market in one permissionless
+/// call. The backer gets their stake and the layer their liability back with
+/// no fee. The bet account is closed and its rent returned to the taker.
+pub fn refund_matched_bet(
+    ctx: Context<RefundMatchedBet>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let matched_bet = &ctx.accounts.matched_bet;
+
+    // Validate inputs
+    require!(event.market_type == MarketType::Exchange, Error::InvalidMarketType);
+    require!(event.outcome == Outcome::Invalid, Error::InvalidOutcome);
+
+    let pot = matched_bet.back_stake.checked_add(matched_bet.lay_liability)
+        .ok_or(Error::OverflowError)?;
+    event.exchange_escrow = event.exchange_escrow.checked_sub(pot)
+        .ok_or(Error::InvariantViolation)?;
+    event.total_paid_out = event.total_paid_out.checked_add(pot)
+        .ok_or(Error::OverflowError)?;
+
+    let refunds = [
+        (&ctx.accounts.backer, &ctx.accounts.backer_token_account, matched_bet.back_stake),
+        (&ctx.accounts.layer, &ctx.accounts.layer_token_account, matched_bet.lay_liability),
+    ];
+    for (wallet, token_account, amount) in refunds {
+        // Refunds may only go to the party that escrowed them
+        let destination = if event.uses_spl_token {
+            let account = TokenAccount::try_deserialize(&mut &token_account.try_borrow_data()?[..])
+                .map_err(|_| Error::InvalidPayoutDestination)?;
+            require!(account.owner == wallet.key(), Error::InvalidPayoutDestination);
+            require!(account.mint == event.token_mint, Error::InvalidMint);
+            token_account.to_account_info()
+        } else {
+            wallet.to_account_info()
+        };
+        transfer_from_vault(
+            event,
+            &ctx.accounts.event_vault.to_account_info(),
+            &ctx.accounts.event_token_vault.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &destination,
+            amount,
+        )?;
+    }
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(MatchedBetRefunded {
+        matched_bet: matched_bet.key(),
+        event: event.key(),
+        backer: matched_bet.backer,
+        layer: matched_bet.layer,
+        back_stake: matched_bet.back_stake,
+        lay_liability: matched_bet.lay_liability,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundMatchedBet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        close = taker,
+        constraint = matched_bet.event == event.key() @ Error::InvalidEvent,
+    )]
+    pub matched_bet: Account<'info, MatchedBet>,
+
+    /// CHECK: Receives the back stake for SOL events; must be the bet's backer
+    #[account(
+        mut,
+        constraint = backer.key() == matched_bet.backer @ Error::InvalidPayoutDestination,
+    )]
+    pub backer: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the lay liability for SOL events; must be the bet's layer
+    #[account(
+        mut,
+        constraint = layer.key() == matched_bet.layer @ Error::InvalidPayoutDestination,
+    )]
+    pub layer: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the matched bet's rent; must be the wallet that opened it
+    #[account(
+        mut,
+        constraint = taker.key() == matched_bet.taker @ Error::Unauthorized,
+    )]
+    pub taker: UncheckedAccount<'info>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub backer_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub layer_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct MatchedBetRefunded {
+    pub matched_bet: Pubkey,
+    pub event: Pubkey,
+    pub backer: Pubkey,
+    pub layer: Pubkey,
+    pub back_stake: u64,
+    pub lay_liability: u64,
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Fixture, FixtureStatus, MarketKind, Outcome};
+use crate::error::Error;
+use crate::instructions::{record_void, record_winner};
+
+/// Reports the final score of a fixture and resolves each of its markets.
+/// Remaining accounts are every market on the fixture (writable). Each market
+/// resolves by its kind; a push voids the market and refunds its stakes.
+/// Markets that were already resolved and scalar markets, which
+/// resolve through `resolve_scalar`, are left as they are.
+pub fn report_score<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReportScore<'info>>,
+    score_a: u16,
+    score_b: u16,
+) -> Result<()> {
+    let fixture = &mut ctx.accounts.fixture;
+    let markets = load_fixture_markets(fixture, ctx.remaining_accounts)?;
+
+    fixture.status = FixtureStatus::Completed;
+    fixture.score_a = score_a;
+    fixture.score_b = score_b;
+
+    for mut event in markets {
+        if event.outcome != Outcome::Undrawn {
+            continue;
+        }
+        // Scalar markets resolve from a reported value instead
+        let Some(outcome) = event.market_kind.resolve(score_a, score_b) else {
+            continue;
+        };
+
+        event.score_reported = true;
+        event.score_a = score_a;
+        event.score_b = score_b;
+
+        if outcome == Outcome::Invalid {
+            record_void(&mut event)?;
+        } else {
+            record_winner(&mut event, outcome)?;
+        }
+
+        emit!(MarketResolved {
+            event: event.key(),
+            fixture: fixture.key(),
+            market_kind: event.market_kind,
+            outcome,
+        });
+
+        event.exit(&crate::ID)?;
+    }
+
+    emit!(ScoreReported {
+        fixture: fixture.key(),
+        fixture_id: fixture.fixture_id,
+        score_a,
+        score_b,
+        markets: fixture.market_count,
+    });
+
+    Ok(())
+}
+
+/// Loads the fixture's markets from `infos`, which must list each of them exactly once
+pub fn load_fixture_markets<'info>(
+    fixture: &Account<'info, Fixture>,
+    infos: &'info [AccountInfo<'info>],
+) -> Result<Vec<Account<'info, Event>>> {
+    require!(infos.len() == fixture.market_count as usize, Error::InvalidFixture);
+
+    let mut markets: Vec<Account<'info, Event>> = Vec::with_capacity(infos.len());
+    for info in infos {
+        require!(info.is_writable, Error::InvalidFixture);
+        require!(
+            markets.iter().all(|market| market.key() != info.key()),
+            Error::InvalidFixture
+        );
+
+        let event: Account<'info, Event> = Account::try_from(info)?;
+        require!(event.fixture == fixture.key(), Error::InvalidFixture);
+        markets.push(event);
+    }
+
+    Ok(markets)
+}
+
+#[derive(Accounts)]
+pub struct ReportScore<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = fixture.authority == authority.key() @ Error::Unauthorized,
+        constraint = fixture.status == FixtureStatus::Scheduled @ Error::FixtureNotScheduled,
+    )]
+    pub fixture: Account<'info, Fixture>,
+}
+
+#[event]
+pub struct MarketResolved {
+    pub event: Pubkey,
+    pub fixture: Pubkey,
+    pub market_kind: MarketKind,
+    pub outcome: Outcome,
+}
+
+#[event]
+pub struct ScoreReported {
+    pub fixture: Pubkey,
+    pub fixture_id: u64,
+    pub score_a: u16,
+    pub score_b: u16,
+    pub markets: u16,
+}
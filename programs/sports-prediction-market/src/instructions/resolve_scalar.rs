@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketKind, MarketType, Outcome};
+use crate::error::Error;
+use crate::instructions::finalize_if_complete;
+use crate::math;
+
+/// Resolves a scalar market at `value`. Values outside the range are clamped,
+/// so the long side takes nothing below `min` and everything above `max`.
+/// Every bet on either side is then settled for its share of its side's pot,
+/// or refunded if one side has no stake to claim its pot.
+pub fn resolve_scalar(
+    ctx: Context<ResolveScalar>,
+    value: i64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    let MarketKind::Scalar { min, max } = event.market_kind else {
+        return err!(Error::InvalidMarketType);
+    };
+    require!(event.market_type == MarketType::Parimutuel, Error::InvalidMarketType);
+    require!(!event.reveal_pending(Clock::get()?.unix_timestamp), Error::RevealPending);
+
+    let total_pool = math::total_pool(event.win_a_amount, event.win_b_amount).map_err(Error::from)?;
+    let long_pot = math::scalar_long_pot(total_pool, value, min, max).map_err(Error::from)?;
+    let short_pot = total_pool - long_pot;
+
+    event.resolved_value = value;
+    event.outcome = if long_pot >= short_pot { Outcome::WinA } else { Outcome::WinB };
+
+    // Both sides are now owed out of the whole pool
+    event.outstanding_stake = total_pool;
+    event.outstanding_losing_share = 0;
+
+    emit!(ScalarResolved {
+        event: event.key(),
+        event_id: event.event_id,
+        value,
+        long_pot: u64::try_from(long_pot).map_err(|_| Error::OverflowError)?,
+        short_pot: u64::try_from(short_pot).map_err(|_| Error::OverflowError)?,
+    });
+
+    // With no bets there is nothing to claim; the pool goes to the treasury
+    finalize_if_complete(event)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveScalar<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct ScalarResolved {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub value: i64,
+    pub long_pot: u64,
+    pub short_pot: u64,
+}
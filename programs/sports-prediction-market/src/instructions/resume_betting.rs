@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Outcome};
+use crate::error::Error;
+
+pub fn resume_betting(ctx: Context<ResumeBetting>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Only a paused, unresolved event can be reopened
+    require!(!event.betting_open, Error::BettingAlreadyOpen);
+    require!(event.outcome == Outcome::Undrawn, Error::EventSettled);
+    // Commits after reveals have begun would no longer be blind
+    require!(!event.private_betting || event.reveal_ends_at == 0, Error::BettingAlreadyClosed);
+
+    event.betting_open = true;
+
+    emit!(BettingResumed {
+        event: event.key(),
+        event_id: event.event_id,
+        resumed_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResumeBetting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct BettingResumed {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub resumed_by: Pubkey,
+}
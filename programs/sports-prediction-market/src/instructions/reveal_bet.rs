@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetCommitment, Event, Outcome};
+use crate::error::Error;
+use crate::instructions::BetCreated;
+
+/// Reveals a committed bet during the reveal window. The escrowed stake
+/// becomes an ordinary bet on `outcome` and joins its pool; the commitment
+/// account closes back to the bettor.
+pub fn reveal_bet(
+    ctx: Context<RevealBet>,
+    outcome: Outcome,
+    salt: [u8; 32],
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let commitment = &ctx.accounts.commitment;
+    let bet = &mut ctx.accounts.bet;
+
+    // Validate inputs
+    require!(outcome == Outcome::WinA || outcome == Outcome::WinB, Error::InvalidOutcome);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !event.betting_open && event.reveal_ends_at != 0 && now < event.reveal_ends_at,
+        Error::RevealNotOpen
+    );
+    require!(
+        BetCommitment::hash(&event.key(), &commitment.authority, outcome, &salt) == commitment.commitment,
+        Error::InvalidCommitment
+    );
+
+    let amount = commitment.amount;
+    bet.bump = [ctx.bumps.bet];
+    bet.authority = commitment.authority;
+    bet.event = event.key();
+    bet.outcome = outcome;
+    bet.amount = amount;
+    bet.settled = false;
+    bet.potential_payout = 0;
+    bet.odds_bps = 0;
+    bet.weighted_amount = amount;
+    bet.early_bird_amount = 0;
+    bet.early_bird_bonus = 0;
+
+    if outcome == Outcome::WinA {
+        event.win_a_amount = event.win_a_amount.checked_add(amount as u128)
+            .ok_or(Error::OverflowError)?;
+        event.weighted_a = event.weighted_a.checked_add(amount as u128)
+            .ok_or(Error::OverflowError)?;
+        event.win_a_count = event.win_a_count.checked_add(1)
+            .ok_or(Error::OverflowError)?;
+    } else {
+        event.win_b_amount = event.win_b_amount.checked_add(amount as u128)
+            .ok_or(Error::OverflowError)?;
+        event.weighted_b = event.weighted_b.checked_add(amount as u128)
+            .ok_or(Error::OverflowError)?;
+        event.win_b_count = event.win_b_count.checked_add(1)
+            .ok_or(Error::OverflowError)?;
+    }
+    event.outstanding_stake = event.outstanding_stake.checked_add(amount as u128)
+        .ok_or(Error::OverflowError)?;
+    event.committed_amount = event.committed_amount.checked_sub(amount)
+        .ok_or(Error::InvariantViolation)?;
+
+    emit!(BetCreated {
+        bet: bet.key(),
+        event: event.key(),
+        authority: bet.authority,
+        outcome,
+        amount,
+        total_amount: amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealBet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"commitment", event.key().as_ref(), authority.key().as_ref()],
+        bump = commitment.bump[0],
+        close = authority,
+    )]
+    pub commitment: Account<'info, BetCommitment>,
+
+    #[account(
+        init,
+        seeds = [b"bet", event.key().as_ref(), authority.key().as_ref()],
+        bump,
+        space = Bet::SPACE,
+        payer = authority,
+    )]
+    pub bet: Account<'info, Bet>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome, Position};
+use crate::error::Error;
+use crate::instructions::SharesTraded;
+use crate::math::{self, Rounding};
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Sells `shares` of `outcome` back to the AMM pool before the result. The
+/// platform fee comes off the collateral released. Fails if less than
+/// `min_amount_out` would be received.
+pub fn sell_shares(
+    ctx: Context<SellShares>,
+    outcome: Outcome,
+    shares: u64,
+    min_amount_out: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let position = &mut ctx.accounts.position;
+
+    // Validate inputs
+    require!(shares > 0, Error::ZeroAmount);
+    require!(outcome == Outcome::WinA || outcome == Outcome::WinB, Error::InvalidOutcome);
+    require!(event.market_type == MarketType::Amm, Error::InvalidMarketType);
+    require!(event.betting_open, Error::BettingClosed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
+
+    let held = position.shares_mut(outcome);
+    *held = held.checked_sub(shares)
+        .ok_or(Error::InsufficientShares)?;
+
+    let (side, other) = if outcome == Outcome::WinA {
+        (event.amm_reserve_a, event.amm_reserve_b)
+    } else {
+        (event.amm_reserve_b, event.amm_reserve_a)
+    };
+    let released = math::cpmm_sell(side, other, shares).map_err(Error::from)?;
+    let fee = math::apply_bps(released as u128, event.fee_bps, Rounding::Down)
+        .map_err(Error::from)? as u64;
+    let amount_out = released - fee;
+    require!(amount_out >= min_amount_out, Error::SlippageExceeded);
+
+    // The pool takes the shares and burns `released` complete sets
+    let new_side = side.checked_add(shares)
+        .and_then(|reserve| reserve.checked_sub(released))
+        .ok_or(Error::OverflowError)?;
+    let new_other = other.checked_sub(released)
+        .ok_or(Error::OverflowError)?;
+    if outcome == Outcome::WinA {
+        (event.amm_reserve_a, event.amm_reserve_b) = (new_side, new_other);
+    } else {
+        (event.amm_reserve_b, event.amm_reserve_a) = (new_side, new_other);
+    }
+    event.amm_collateral = event.amm_collateral.checked_sub(released)
+        .ok_or(Error::InvariantViolation)?;
+    event.accrued_fees = event.accrued_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+    event.total_fees = event.total_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.owner.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        amount_out,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(SharesTraded {
+        event: event.key(),
+        owner: position.owner,
+        outcome,
+        shares,
+        collateral: amount_out,
+        fee,
+        is_buy: false,
+        reserve_a: event.amm_reserve_a,
+        reserve_b: event.amm_reserve_b,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SellShares<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"position", event.key().as_ref(), owner.key().as_ref()],
+        bump = position.bump[0],
+    )]
+    pub position: Account<'info, Position>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
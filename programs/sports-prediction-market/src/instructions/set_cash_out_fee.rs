@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+
+/// Sets the fee charged on early cash-outs, in basis points of the cash-out
+/// value. Only allowed before the first bet so bettors know the terms.
+pub fn set_cash_out_fee(
+    ctx: Context<SetCashOutFee>,
+    cash_out_fee_bps: u32,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(cash_out_fee_bps <= 10000, Error::InvalidFee);
+    require!(event.market_type == MarketType::Parimutuel, Error::InvalidMarketType);
+    require!(event.win_a_count == 0 && event.win_b_count == 0, Error::InvalidMarketType);
+
+    event.cash_out_fee_bps = cash_out_fee_bps;
+
+    emit!(CashOutFeeSet {
+        event: event.key(),
+        event_id: event.event_id,
+        cash_out_fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCashOutFee<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct CashOutFeeSet {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub cash_out_fee_bps: u32,
+}
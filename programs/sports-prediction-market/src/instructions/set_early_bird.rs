@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+
+/// Configures the early-bird promo. The first `early_bird_cap` staked on each
+/// side, plus every stake placed before `early_bird_ends_at`, reserves a bonus
+/// of `bonus_bps` of that stake from the promo budget, paid on top of its
+/// winnings if its side wins. Only allowed before the first bet.
+pub fn set_early_bird(
+    ctx: Context<SetEarlyBird>,
+    early_bird_cap: u64,
+    early_bird_ends_at: i64,
+    bonus_bps: u32,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(bonus_bps <= 10000, Error::InvalidFee);
+    require!(event.win_a_count == 0 && event.win_b_count == 0, Error::InvalidMarketType);
+    require!(
+        event.market_type == MarketType::Parimutuel
+            && !event.market_kind.is_scalar()
+            && !event.private_betting,
+        Error::InvalidMarketType
+    );
+
+    event.early_bird_cap = early_bird_cap;
+    event.early_bird_ends_at = early_bird_ends_at;
+    event.early_bird_bonus_bps = bonus_bps;
+
+    emit!(EarlyBirdSet {
+        event: event.key(),
+        event_id: event.event_id,
+        early_bird_cap,
+        early_bird_ends_at,
+        bonus_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetEarlyBird<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct EarlyBirdSet {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub early_bird_cap: u64,
+    pub early_bird_ends_at: i64,
+    pub bonus_bps: u32,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, FeeShare, Outcome};
+use crate::error::Error;
+
+/// Replaces the event's fee split. The treasury co-signs, since the split
+/// decides how much of the platform fee it keeps.
+pub fn set_fee_split(
+    ctx: Context<SetFeeSplit>,
+    shares: Vec<FeeShare>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // The split is fixed once settlement starts
+    require!(event.outcome == Outcome::Undrawn, Error::EventSettled);
+    require!(shares.is_empty() || FeeShare::valid_split(&shares), Error::InvalidFeeSplit);
+
+    event.fee_split = shares;
+
+    emit!(FeeSplitUpdated {
+        event: event.key(),
+        event_id: event.event_id,
+        shares: event.fee_split.clone(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeSplit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        constraint = treasury.key() == event.platform_fee_account @ Error::Unauthorized,
+    )]
+    pub treasury: Signer<'info>,
+}
+
+#[event]
+pub struct FeeSplitUpdated {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub shares: Vec<FeeShare>,
+}
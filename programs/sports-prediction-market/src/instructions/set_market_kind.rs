@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketKind, MarketType, Outcome};
+use crate::error::Error;
+
+/// Makes the event a derived market, such as a handicap line or an
+/// over/under, resolved by `report_score` on its fixture. Only allowed
+/// before the first bet, since it changes what the outcomes mean.
+pub fn set_market_kind(
+    ctx: Context<SetMarketKind>,
+    market_kind: MarketKind,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Sealed commitments count as bets; they reveal against the new kind
+    require!(
+        event.win_a_count == 0 && event.win_b_count == 0 && event.commit_count == 0,
+        Error::InvalidMarketType
+    );
+    if let MarketKind::Scalar { min, max } = market_kind {
+        // Scalar markets split a plain pari-mutuel pool; stake weighting and
+        // early-bird bonuses assume a single winning side
+        require!(min < max, Error::InvalidScalarRange);
+        require!(event.market_type == MarketType::Parimutuel, Error::InvalidMarketType);
+        require!(
+            !event.stake_weighted() && event.early_bird_bonus_bps == 0,
+            Error::InvalidMarketType
+        );
+    }
+
+    event.market_kind = market_kind;
+
+    emit!(MarketKindSet {
+        event: event.key(),
+        event_id: event.event_id,
+        fixture: event.fixture,
+        market_kind,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMarketKind<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct MarketKindSet {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub fixture: Pubkey,
+    pub market_kind: MarketKind,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+
+/// Posts decimal odds for a fixed-odds book. Calling it on a pari-mutuel
+/// event with no bets yet converts the event to a fixed-odds market. New odds
+/// only apply to later bets; existing bets keep the odds they locked in.
+pub fn set_odds(
+    ctx: Context<SetOdds>,
+    odds_a_bps: u32,
+    odds_b_bps: u32,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Decimal odds must pay back more than the stake
+    require!(odds_a_bps > 10000 && odds_b_bps > 10000, Error::InvalidOdds);
+
+    if event.market_type == MarketType::Parimutuel {
+        require!(event.can_change_market_type(MarketType::FixedOdds), Error::InvalidMarketType);
+        event.market_type = MarketType::FixedOdds;
+    }
+    require!(event.market_type == MarketType::FixedOdds, Error::InvalidMarketType);
+
+    event.odds_a_bps = odds_a_bps;
+    event.odds_b_bps = odds_b_bps;
+
+    emit!(OddsUpdated {
+        event: event.key(),
+        event_id: event.event_id,
+        odds_a_bps,
+        odds_b_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOdds<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct OddsUpdated {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub odds_a_bps: u32,
+    pub odds_b_bps: u32,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+
+/// Switches the event to private betting. Bettors commit a hash of their
+/// side with escrowed stake while betting is open and reveal it in the
+/// `reveal_window` seconds after `close_betting`; pools only count revealed
+/// bets. Unrevealed stakes are forfeited to the treasury if
+/// `forfeit_unrevealed`, otherwise refunded. Only allowed before the first bet.
+pub fn set_private_betting(
+    ctx: Context<SetPrivateBetting>,
+    reveal_window: i64,
+    forfeit_unrevealed: bool,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(reveal_window > 0, Error::InvalidRevealWindow);
+    require!(event.betting_open, Error::BettingClosed);
+    require!(
+        event.win_a_count == 0 && event.win_b_count == 0 && event.commit_count == 0,
+        Error::InvalidMarketType
+    );
+    // Revealed stakes carry no placement time for weighting or early-bird promos
+    require!(
+        event.market_type == MarketType::Parimutuel
+            && !event.stake_weighted()
+            && event.early_bird_bonus_bps == 0,
+        Error::InvalidMarketType
+    );
+
+    event.private_betting = true;
+    event.reveal_window = reveal_window;
+    event.forfeit_unrevealed = forfeit_unrevealed;
+
+    emit!(PrivateBettingSet {
+        event: event.key(),
+        event_id: event.event_id,
+        reveal_window,
+        forfeit_unrevealed,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPrivateBetting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct PrivateBettingSet {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub reveal_window: i64,
+    pub forfeit_unrevealed: bool,
+}
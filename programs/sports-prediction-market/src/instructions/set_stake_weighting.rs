@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+
+/// Sets when betting closes and how stakes lose weight as it approaches.
+/// A stake placed before `decay_starts_at` has full weight; the weight then
+/// falls linearly to `weight_floor_bps` at `betting_closes_at`. Winners split
+/// the losing pool by weighted stake. A zero floor only sets the deadline.
+/// Only allowed before the first bet.
+pub fn set_stake_weighting(
+    ctx: Context<SetStakeWeighting>,
+    betting_closes_at: i64,
+    decay_starts_at: i64,
+    weight_floor_bps: u32,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validate inputs
+    require!(event.win_a_count == 0 && event.win_b_count == 0, Error::InvalidStakeWeighting);
+    require!(betting_closes_at == 0 || betting_closes_at > now, Error::InvalidStakeWeighting);
+    if weight_floor_bps > 0 {
+        // Revealed stakes carry no placement time to weight by
+        require!(
+            event.market_type == MarketType::Parimutuel
+                && !event.market_kind.is_scalar()
+                && !event.private_betting,
+            Error::InvalidMarketType
+        );
+        require!(weight_floor_bps <= 10000, Error::InvalidStakeWeighting);
+        require!(
+            betting_closes_at > 0 && decay_starts_at <= betting_closes_at,
+            Error::InvalidStakeWeighting
+        );
+    }
+
+    event.betting_closes_at = betting_closes_at;
+    event.decay_starts_at = decay_starts_at;
+    event.weight_floor_bps = weight_floor_bps;
+
+    emit!(StakeWeightingSet {
+        event: event.key(),
+        event_id: event.event_id,
+        betting_closes_at,
+        decay_starts_at,
+        weight_floor_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetStakeWeighting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct StakeWeightingSet {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub betting_closes_at: i64,
+    pub decay_starts_at: i64,
+    pub weight_floor_bps: u32,
+}
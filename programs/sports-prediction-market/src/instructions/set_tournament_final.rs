@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Outcome, Tournament};
+use crate::error::Error;
+
+/// Marks the bracket match that decides the champion; announcing its winner
+/// resolves the outright pool
+pub fn set_tournament_final(
+    ctx: Context<SetTournamentFinal>,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let event = &mut ctx.accounts.event;
+
+    require!(tournament.final_event == Pubkey::default(), Error::InvalidBracket);
+    require!(event.next_event == Pubkey::default(), Error::InvalidBracket);
+    require!(
+        event.tournament == Pubkey::default() || event.tournament == tournament.key(),
+        Error::InvalidBracket
+    );
+    require!(event.fixture == Pubkey::default(), Error::InvalidBracket);
+
+    tournament.final_event = event.key();
+    event.tournament = tournament.key();
+
+    emit!(TournamentFinalSet {
+        tournament: tournament.key(),
+        final_event: event.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTournamentFinal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = tournament.authority == authority.key() @ Error::Unauthorized,
+        constraint = tournament.champion.is_none() @ Error::EventSettled,
+        constraint = !tournament.voided @ Error::EventSettled,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct TournamentFinalSet {
+    pub tournament: Pubkey,
+    pub final_event: Pubkey,
+}
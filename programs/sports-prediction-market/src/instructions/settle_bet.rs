@@ -1,124 +1,97 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
-use crate::state::{Event, Bet, Outcome};
+use crate::state::{Event, Bet, FeeMode, MarketKind, MarketType, Outcome};
 use crate::error::Error;
+use crate::math::{self, Payout};
+use crate::vault::{assert_solvent, transfer_from_vault};
 
 pub fn settle_bet(
     ctx: Context<SettleBet>,
 ) -> Result<()> {
     let bet = &mut ctx.accounts.bet;
     let event = &mut ctx.accounts.event;
+
+    let Payout { payout, .. } = record_settlement(event, bet)?;
+
+    // Transfer payout if any
+    let payout_destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.authority.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &payout_destination,
+        payout,
+    )?;
+
+    // Sweep rounding dust to the treasury after the last winning claim
+    finalize_if_complete(event)?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
     
+    Ok(())
+}
+
+/// Marks `bet` settled and books its payout and fee against the event.
+/// The caller is responsible for transferring the returned payout, which
+/// includes any early-bird bonus paid out of the promo budget.
+pub fn record_settlement(event: &mut Account<Event>, bet: &mut Account<Bet>) -> Result<Payout> {
     // Validate inputs
     require!(!bet.settled, Error::BetSettled);
     require!(event.outcome != Outcome::Undrawn, Error::EventNotSettled);
+    require!(!event.merkle_settlement, Error::MerkleSettlementActive);
     require!(bet.event == event.key(), Error::InvalidEvent);
-    
-    // Calculate winnings
-    let (_total_pool, winning_pool, losing_pool) = if event.outcome == Outcome::WinA {
-        (event.win_a_amount + event.win_b_amount, event.win_a_amount, event.win_b_amount)
-    } else {
-        (event.win_a_amount + event.win_b_amount, event.win_b_amount, event.win_a_amount)
-    };
-    
-    let mut payout = 0u64;
-    let mut platform_fee = 0u64;
 
-    if bet.outcome == event.outcome {
-        // Winner - calculate proportional payout
-        if winning_pool > 0 {
-            let bet_amount = bet.amount as u128;
-            let fee_amount = (bet_amount * event.fee_bps as u128) / 10000;
-            let net_bet_amount = bet_amount - fee_amount;
-
-            // Calculate proportional share of the losing pool
-            let share_of_losing_pool = (net_bet_amount * losing_pool) / winning_pool;
-            payout = (net_bet_amount + share_of_losing_pool) as u64;
-            platform_fee = fee_amount as u64;
-        }
-    } else {
-        // Loser - no payout
-        payout = 0;
-    }
+    let mut settlement = bet_settlement(event, bet)?;
+    let Payout { payout, fee: platform_fee, .. } = settlement;
+    let bonus = early_bird_bonus(event, bet);
 
     // Mark bet as settled
     bet.settled = true;
 
-    // Transfer platform fee if any
-    if platform_fee > 0 {
-        if event.uses_spl_token {
-            // Transfer SPL token fee
-            let event_id_bytes = event.event_id.to_le_bytes();
-            let seeds = &[
-                b"event",
-                event_id_bytes.as_ref(),
-                &[event.bump[0]],
-            ];
-            let signer = &[&seeds[..]];
-
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.event_token_vault.to_account_info(),
-                to: ctx.accounts.platform_fee_token_account.to_account_info(),
-                authority: event.to_account_info(),
-            };
-            let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                cpi_accounts,
-                signer,
-            );
-            token::transfer(cpi_ctx, platform_fee)?;
-        } else {
-            // Transfer SOL fee
-            let vault_info = ctx.accounts.event_vault.to_account_info();
-            let platform_fee_info = ctx.accounts.platform_fee_account.to_account_info();
+    // Fees stay in the vault until the treasury withdraws them
+    event.accrued_fees = event.accrued_fees.checked_add(platform_fee)
+        .ok_or(Error::OverflowError)?;
+    event.total_fees = event.total_fees.checked_add(platform_fee)
+        .ok_or(Error::OverflowError)?;
+    event.total_paid_out = event.total_paid_out.checked_add(payout)
+        .ok_or(Error::OverflowError)?;
+    let refunded = event.refunds_all_stakes();
+    if (refunded || bet.outcome == event.outcome) && event.market_type == MarketType::FixedOdds {
+        event.winners_settled += 1;
+        event.outstanding_payouts = event.outstanding_payouts.checked_sub(payout)
+            .ok_or(Error::InvariantViolation)?;
+    } else if refunded {
+        event.winners_settled += 1;
+        event.outstanding_stake = event.outstanding_stake.checked_sub(bet.amount as u128)
+            .ok_or(Error::InvariantViolation)?;
+    } else if event.market_kind.is_scalar() {
+        // Both sides are paid out of the whole pool
+        event.winners_settled += 1;
+        event.outstanding_stake = event.outstanding_stake.checked_sub(payout as u128 + platform_fee as u128)
+            .ok_or(Error::InvariantViolation)?;
+    } else if bet.outcome == event.outcome {
+        event.winners_settled += 1;
 
-            // Ensure vault has enough lamports for fee
-            let vault_lamports = vault_info.lamports();
-            require!(vault_lamports >= platform_fee, Error::InsufficientFunds);
-
-            **vault_info.try_borrow_mut_lamports()? -= platform_fee;
-            **platform_fee_info.try_borrow_mut_lamports()? += platform_fee;
-        }
+        // The stake comes back out of the winners' side; the rest of the
+        // payout and fee comes out of the losing pool
+        let stake = bet.amount as u128;
+        let from_losing_pool = (payout as u128 + platform_fee as u128)
+            .checked_sub(stake)
+            .ok_or(Error::InvariantViolation)?;
+        event.outstanding_stake = event.outstanding_stake.checked_sub(stake)
+            .ok_or(Error::InvariantViolation)?;
+        event.outstanding_losing_share = event.outstanding_losing_share.checked_sub(from_losing_pool)
+            .ok_or(Error::InvariantViolation)?;
     }
 
-    // Transfer payout if any
-    if payout > 0 {
-        if event.uses_spl_token {
-            // Transfer SPL tokens
-            let event_id_bytes = event.event_id.to_le_bytes();
-            let seeds = &[
-                b"event",
-                event_id_bytes.as_ref(),
-                &[event.bump[0]],
-            ];
-            let signer = &[&seeds[..]];
-
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.event_token_vault.to_account_info(),
-                to: ctx.accounts.user_token_account.to_account_info(),
-                authority: event.to_account_info(),
-            };
-            let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                cpi_accounts,
-                signer,
-            );
-            token::transfer(cpi_ctx, payout)?;
-        } else {
-            // Transfer SOL
-            let vault_info = ctx.accounts.event_vault.to_account_info();
-            let authority_info = ctx.accounts.authority.to_account_info();
-
-            // Ensure vault has enough lamports
-            let vault_lamports = vault_info.lamports();
-            require!(vault_lamports >= payout, Error::InsufficientFunds);
-
-            // Move lamports directly (allowed because the program owns the event account)
-            **vault_info.try_borrow_mut_lamports()? -= payout;
-            **authority_info.try_borrow_mut_lamports()? += payout;
-        }
-    }
-    
     emit!(BetSettled {
         bet: bet.key(),
         event: event.key(),
@@ -126,12 +99,139 @@ pub fn settle_bet(
         outcome: bet.outcome,
         amount: bet.amount,
         payout,
+        fee: platform_fee,
+        bonus,
         won: bet.outcome == event.outcome,
     });
-    
+
+    // The bonus comes out of the promo budget, not the pools
+    if bonus > 0 {
+        let reserved = if event.outcome == Outcome::WinA {
+            &mut event.promo_reserved_a
+        } else {
+            &mut event.promo_reserved_b
+        };
+        *reserved = reserved.checked_sub(bonus)
+            .ok_or(Error::InvariantViolation)?;
+        event.promo_budget = event.promo_budget.checked_sub(bonus)
+            .ok_or(Error::InvariantViolation)?;
+        settlement.payout = payout.checked_add(bonus)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    Ok(settlement)
+}
+
+/// Early-bird bonus owed to `bet` on a resolved event: the amount it
+/// reserved, if its side won
+pub fn early_bird_bonus(event: &Event, bet: &Bet) -> u64 {
+    let won = matches!(event.outcome, Outcome::WinA | Outcome::WinB) && bet.outcome == event.outcome;
+    if won && event.market_type == MarketType::Parimutuel && !event.market_kind.is_scalar() {
+        bet.early_bird_bonus
+    } else {
+        0
+    }
+}
+
+/// What `bet` is owed on a resolved event, before any accounting is updated
+pub fn bet_settlement(event: &Event, bet: &Bet) -> Result<Payout> {
+    let (winning_pool, losing_pool) = if event.outcome == Outcome::WinA {
+        (event.win_a_amount, event.win_b_amount)
+    } else {
+        (event.win_b_amount, event.win_a_amount)
+    };
+
+    let settlement = if event.refunds_all_stakes() {
+        // Void event, or nobody backed the winner - the stake comes back without a fee
+        Payout {
+            payout: bet.amount,
+            fee: 0,
+            net_stake: bet.amount,
+        }
+    } else if let MarketKind::Scalar { min, max } = event.market_kind {
+        // Scalar market - each side splits its linear share of the pool
+        let total_pool = math::total_pool(event.win_a_amount, event.win_b_amount)
+            .map_err(Error::from)?;
+        let long_pot = math::scalar_long_pot(total_pool, event.resolved_value, min, max)
+            .map_err(Error::from)?;
+        let (side_pool, side_pot) = if bet.outcome == Outcome::WinA {
+            (event.win_a_amount, long_pot)
+        } else {
+            (event.win_b_amount, total_pool - long_pot)
+        };
+        math::scalar_payout(bet.amount, event.fee_bps, side_pool, side_pot).map_err(Error::from)?
+    } else if bet.outcome == event.outcome && event.market_type == MarketType::FixedOdds {
+        // Winner - paid at the odds locked in when the bet was placed
+        Payout {
+            payout: bet.potential_payout,
+            fee: 0,
+            net_stake: bet.amount,
+        }
+    } else if bet.outcome == event.outcome {
+        // Winner - calculate proportional payout
+        parimutuel_winner_payout(event, bet, winning_pool, losing_pool)?
+    } else {
+        // Loser - no payout
+        Payout::default()
+    };
+
+    Ok(settlement)
+}
+
+/// What a winning pari-mutuel bet is paid out of the given pools. On a
+/// stake-weighted event the losing pool is split by weighted stake instead.
+pub fn parimutuel_winner_payout(
+    event: &Event,
+    bet: &Bet,
+    winning_pool: u128,
+    losing_pool: u128,
+) -> Result<Payout> {
+    if !event.stake_weighted() {
+        return calculate_payout(event.fee_mode, event.fee_bps, bet.amount, winning_pool, losing_pool);
+    }
+
+    let total_weight = if bet.outcome == Outcome::WinA { event.weighted_a } else { event.weighted_b };
+    Ok(math::weighted_payout(
+        bet.amount,
+        bet.weighted_amount,
+        event.fee_bps,
+        event.fee_mode == FeeMode::NetWinnings,
+        total_weight,
+        losing_pool,
+    ).map_err(Error::from)?)
+}
+
+/// Finalizes settlement once the last winning bet has been paid
+pub fn finalize_if_complete(event: &mut Account<Event>) -> Result<()> {
+    if let Some(dust) = event.finalize_settlement()? {
+        emit!(SettlementFinalized {
+            event: event.key(),
+            event_id: event.event_id,
+            total_paid_out: event.total_paid_out,
+            total_fees: event.total_fees,
+            dust,
+        });
+    }
     Ok(())
 }
 
+/// Settles a winning stake under the event's fee mode. All amounts round
+/// down, so the vault never pays out more than the pool holds.
+pub fn calculate_payout(
+    fee_mode: FeeMode,
+    fee_bps: u32,
+    bet_amount: u64,
+    winning_pool: u128,
+    losing_pool: u128,
+) -> Result<Payout> {
+    let settle = match fee_mode {
+        FeeMode::Stake => math::stake_fee_payout,
+        FeeMode::NetWinnings => math::net_winnings_payout,
+        FeeMode::PoolTake => math::pool_take_payout,
+    };
+    Ok(settle(bet_amount, fee_bps, winning_pool, losing_pool).map_err(Error::from)?)
+}
+
 #[derive(Accounts)]
 pub struct SettleBet<'info> {
     #[account(mut)]
@@ -158,13 +258,6 @@ pub struct SettleBet<'info> {
     )]
     pub event_vault: UncheckedAccount<'info>,
 
-    /// CHECK: Platform fee collection account (SOL or SPL token account depending on event type)
-    #[account(
-        mut,
-        constraint = platform_fee_account.key() == event.platform_fee_account @ Error::Unauthorized,
-    )]
-    pub platform_fee_account: UncheckedAccount<'info>,
-
     // SPL token accounts (only used if event.uses_spl_token = true)
     /// CHECK: Validated in instruction logic when uses_spl_token is true
     #[account(mut)]
@@ -174,10 +267,6 @@ pub struct SettleBet<'info> {
     #[account(mut)]
     pub event_token_vault: AccountInfo<'info>,
 
-    /// CHECK: Platform fee token account (only used if uses_spl_token is true)
-    #[account(mut)]
-    pub platform_fee_token_account: AccountInfo<'info>,
-
     /// CHECK: Validated in instruction logic when uses_spl_token is true
     pub token_mint: AccountInfo<'info>,
 
@@ -195,5 +284,16 @@ pub struct BetSettled {
     pub outcome: Outcome,
     pub amount: u64,
     pub payout: u64,
+    pub fee: u64,
+    pub bonus: u64,
     pub won: bool,
 }
+
+#[event]
+pub struct SettlementFinalized {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub total_paid_out: u64,
+    pub total_fees: u64,
+    pub dust: u64,
+}
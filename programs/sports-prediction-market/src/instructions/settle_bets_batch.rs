@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{Event, Bet};
+use crate::error::Error;
+use crate::instructions::{finalize_if_complete, record_settlement};
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Settles many bets on a resolved event in one permissionless call.
+///
+/// Remaining accounts are `(bet, owner, destination)` groups. The owner is the
+/// bet owner's wallet; the destination is that same wallet for SOL events, or a
+/// token account the owner holds in the event mint for SPL events. Winnings go
+/// straight to the destination and each bet account is closed to the owner's
+/// wallet, returning its rent there.
+pub fn settle_bets_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleBetsBatch<'info>>,
+) -> Result<()> {
+    let groups = ctx.remaining_accounts.chunks_exact(3);
+    require!(groups.len() > 0 && groups.remainder().is_empty(), Error::InvalidBatch);
+
+    let event_vault = ctx.accounts.event_vault.to_account_info();
+    let event_token_vault = ctx.accounts.event_token_vault.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let event = &mut ctx.accounts.event;
+
+    for group in groups {
+        let (bet_info, owner, destination) = (&group[0], &group[1], &group[2]);
+        let mut bet: Account<'info, Bet> = Account::try_from(bet_info)?;
+
+        // Payouts and rent may only go to the bet owner
+        require!(owner.key() == bet.authority, Error::InvalidPayoutDestination);
+        if event.uses_spl_token {
+            let token_account = TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])
+                .map_err(|_| Error::InvalidPayoutDestination)?;
+            require!(token_account.owner == bet.authority, Error::InvalidPayoutDestination);
+            require!(token_account.mint == event.token_mint, Error::InvalidMint);
+        } else {
+            require!(destination.key() == bet.authority, Error::InvalidPayoutDestination);
+        }
+
+        let payout = record_settlement(event, &mut bet)?.payout;
+        transfer_from_vault(
+            event,
+            &event_vault,
+            &event_token_vault,
+            &token_program,
+            destination,
+            payout,
+        )?;
+
+        bet.close(owner.clone())?;
+    }
+
+    // Sweep rounding dust to the treasury after the last winning claim
+    finalize_if_complete(event)?;
+
+    assert_solvent(event, &event_vault, &event_token_vault)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleBetsBatch<'info> {
+    /// Anyone may crank settlement; the keeper only pays transaction fees
+    pub keeper: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, MatchedBet, Outcome};
+use crate::error::Error;
+use crate::math::{self, Rounding};
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Pays a matched bet to its winner once the result is announced. The winner
+/// takes both sides' escrow less `fee_bps` commission on their net winnings.
+/// The bet account is closed and its rent returned to the taker who opened it.
+pub fn settle_matched_bet(
+    ctx: Context<SettleMatchedBet>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let matched_bet = &ctx.accounts.matched_bet;
+
+    // Validate inputs
+    require!(event.market_type == MarketType::Exchange, Error::InvalidMarketType);
+    require!(event.outcome != Outcome::Undrawn, Error::EventNotSettled);
+    // A voided market unwinds through refund_matched_bet
+    require!(event.outcome != Outcome::Invalid, Error::InvalidOutcome);
+
+    let backer_won = matched_bet.outcome == event.outcome;
+    let (winner, net_winnings) = if backer_won {
+        (matched_bet.backer, matched_bet.lay_liability)
+    } else {
+        (matched_bet.layer, matched_bet.back_stake)
+    };
+    require!(ctx.accounts.winner.key() == winner, Error::Unauthorized);
+
+    let pot = matched_bet.back_stake.checked_add(matched_bet.lay_liability)
+        .ok_or(Error::OverflowError)?;
+    let fee = math::apply_bps(net_winnings as u128, event.fee_bps, Rounding::Down)
+        .map_err(Error::from)? as u64;
+    let payout = pot.checked_sub(fee).ok_or(Error::InvariantViolation)?;
+
+    event.exchange_escrow = event.exchange_escrow.checked_sub(pot)
+        .ok_or(Error::InvariantViolation)?;
+    event.accrued_fees = event.accrued_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+    event.total_fees = event.total_fees.checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+    event.total_paid_out = event.total_paid_out.checked_add(payout)
+        .ok_or(Error::OverflowError)?;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.user_token_account.to_account_info()
+    } else {
+        ctx.accounts.winner.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        payout,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(MatchedBetSettled {
+        matched_bet: matched_bet.key(),
+        event: event.key(),
+        winner,
+        backer_won,
+        payout,
+        fee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleMatchedBet<'info> {
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    #[account(mut)]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        close = taker,
+        constraint = matched_bet.event == event.key() @ Error::InvalidEvent,
+    )]
+    pub matched_bet: Account<'info, MatchedBet>,
+
+    /// CHECK: Receives the matched bet's rent; must be the wallet that opened it
+    #[account(
+        mut,
+        constraint = taker.key() == matched_bet.taker @ Error::Unauthorized,
+    )]
+    pub taker: UncheckedAccount<'info>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct MatchedBetSettled {
+    pub matched_bet: Pubkey,
+    pub event: Pubkey,
+    pub winner: Pubkey,
+    pub backer_won: bool,
+    pub payout: u64,
+    pub fee: u64,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Outcome, Parlay, ParlayLeg, ParlayPool};
+use crate::error::Error;
+use crate::math::{self, Rounding};
+use crate::vault::assert_pool_solvent;
+
+/// Settles a parlay once every leg event is resolved. Remaining accounts are
+/// the writable leg events, in leg order. Legs on void events are dropped and the rest
+/// still count: the parlay pays the stake times the odds of the remaining legs
+/// if they all won (just the stake if every leg is void), and nothing if any
+/// leg lost. The parlay account is closed to its owner.
+pub fn settle_parlay<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleParlay<'info>>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let parlay = &ctx.accounts.parlay;
+
+    require!(ctx.remaining_accounts.len() == parlay.legs.len(), Error::InvalidParlay);
+
+    let mut live_odds = Vec::with_capacity(parlay.legs.len());
+    let mut won = true;
+    for (event_info, leg) in ctx.remaining_accounts.iter().zip(&parlay.legs) {
+        let mut event: Account<'info, Event> = Account::try_from(event_info)?;
+        require!(event.key() == leg.event, Error::InvalidEvent);
+        require!(event.outcome != Outcome::Undrawn, Error::EventNotSettled);
+
+        event.open_parlay_legs = event.open_parlay_legs.checked_sub(1)
+            .ok_or(Error::InvariantViolation)?;
+        event.exit(&crate::ID)?;
+
+        if event.outcome == Outcome::Invalid {
+            continue;
+        }
+        won &= event.outcome == leg.outcome;
+        live_odds.push(leg.odds_bps);
+    }
+    let void_legs = (parlay.legs.len() - live_odds.len()) as u8;
+
+    let payout = if won {
+        let combined_odds_bps = ParlayLeg::combined_odds_bps(live_odds)?;
+        let payout = math::mul_div(parlay.stake as u128, combined_odds_bps, math::BPS_DENOMINATOR, Rounding::Down)
+            .map_err(Error::from)?;
+        u64::try_from(payout).map_err(|_| Error::OverflowError)?
+    } else {
+        0
+    };
+
+    // The pool keeps losing stakes and pays winners out of stake plus bankroll
+    pool.open_stake = pool.open_stake.checked_sub(parlay.stake)
+        .ok_or(Error::InvariantViolation)?;
+    pool.liability = pool.liability.checked_sub(parlay.potential_payout)
+        .ok_or(Error::InvariantViolation)?;
+    require!(payout <= parlay.potential_payout, Error::InvariantViolation);
+    pool.bankroll = (pool.bankroll as u128 + parlay.stake as u128)
+        .checked_sub(payout as u128)
+        .and_then(|bankroll| u64::try_from(bankroll).ok())
+        .ok_or(Error::InvariantViolation)?;
+
+    // Move lamports directly (allowed because the program owns the pool account)
+    **pool.to_account_info().try_borrow_mut_lamports()? -= payout;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += payout;
+
+    assert_pool_solvent(pool)?;
+
+    emit!(ParlaySettled {
+        parlay: parlay.key(),
+        pool: pool.key(),
+        owner: parlay.owner,
+        won,
+        void_legs,
+        payout,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleParlay<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub pool: Account<'info, ParlayPool>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = parlay.owner == owner.key() @ Error::Unauthorized,
+        constraint = parlay.pool == pool.key() @ Error::InvalidParlay,
+    )]
+    pub parlay: Account<'info, Parlay>,
+}
+
+#[event]
+pub struct ParlaySettled {
+    pub parlay: Pubkey,
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub won: bool,
+    pub void_legs: u8,
+    pub payout: u64,
+}
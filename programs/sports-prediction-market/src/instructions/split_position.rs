@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_to_vault};
+
+/// Deposits `amount` of collateral and mints `amount` of both outcome tokens
+pub fn split_position(
+    ctx: Context<SplitPosition>,
+    amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(event.market_type == MarketType::ConditionalTokens, Error::InvalidMarketType);
+    require!(event.betting_open, Error::BettingClosed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event.betting_closes_at == 0 || now < event.betting_closes_at,
+        Error::BettingClosed
+    );
+
+    event.conditional_collateral = event.conditional_collateral.checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+
+    transfer_to_vault(
+        event,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    // The event PDA is the mint authority of both outcome tokens
+    let event_id_bytes = event.event_id.to_le_bytes();
+    let seeds = &[
+        b"event",
+        event_id_bytes.as_ref(),
+        &[event.bump[0]],
+    ];
+    let signer = &[&seeds[..]];
+
+    for (mint, destination) in [
+        (&ctx.accounts.outcome_mint_a, &ctx.accounts.user_outcome_a),
+        (&ctx.accounts.outcome_mint_b, &ctx.accounts.user_outcome_b),
+    ] {
+        let cpi_accounts = MintTo {
+            mint: mint.to_account_info(),
+            to: destination.to_account_info(),
+            authority: event.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        token::mint_to(cpi_ctx, amount)?;
+    }
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(PositionSplit {
+        event: event.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SplitPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(mut, address = event.outcome_mint_a @ Error::InvalidMint)]
+    pub outcome_mint_a: Account<'info, Mint>,
+
+    #[account(mut, address = event.outcome_mint_b @ Error::InvalidMint)]
+    pub outcome_mint_b: Account<'info, Mint>,
+
+    #[account(mut, token::mint = outcome_mint_a)]
+    pub user_outcome_a: Account<'info, TokenAccount>,
+
+    #[account(mut, token::mint = outcome_mint_b)]
+    pub user_outcome_b: Account<'info, TokenAccount>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL collateral accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub user_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PositionSplit {
+    pub event: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, Bet, Outcome};
+use crate::error::Error;
+
+/// Hands an unsettled bet to another wallet. Bet addresses are derived from
+/// their owner, so the position moves to the recipient's bet PDA and the old
+/// account is closed; settlement then pays the recipient. The recipient must
+/// not already hold a bet on the event.
+pub fn transfer_bet(
+    ctx: Context<TransferBet>,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let bet = &ctx.accounts.bet;
+    let new_bet = &mut ctx.accounts.new_bet;
+    let new_owner = ctx.accounts.new_owner.key();
+
+    // Validate inputs
+    require!(!bet.settled, Error::BetSettled);
+    require!(!event.merkle_settlement, Error::MerkleSettlementActive);
+    require!(new_owner != bet.authority, Error::InvalidBetRecipient);
+    require!(
+        event.max_wallet_exposure == 0 || bet.amount <= event.max_wallet_exposure,
+        Error::WalletExposureExceeded
+    );
+
+    new_bet.bump = [ctx.bumps.new_bet];
+    new_bet.authority = new_owner;
+    new_bet.event = bet.event;
+    new_bet.outcome = bet.outcome;
+    new_bet.amount = bet.amount;
+    new_bet.settled = false;
+    new_bet.potential_payout = bet.potential_payout;
+    new_bet.odds_bps = bet.odds_bps;
+    new_bet.weighted_amount = bet.weighted_amount;
+    new_bet.early_bird_amount = bet.early_bird_amount;
+    new_bet.early_bird_bonus = bet.early_bird_bonus;
+
+    emit!(BetTransferred {
+        event: event.key(),
+        from_bet: bet.key(),
+        to_bet: new_bet.key(),
+        from: bet.authority,
+        to: new_owner,
+        outcome: bet.outcome,
+        amount: bet.amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferBet<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        constraint = bet.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(
+        constraint = bet.event == event.key() @ Error::InvalidEvent,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: Any wallet can receive a bet; it only becomes the new bet's authority
+    pub new_owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [b"bet", event.key().as_ref(), new_owner.key().as_ref()],
+        bump,
+        space = Bet::SPACE,
+        payer = authority,
+    )]
+    pub new_bet: Account<'info, Bet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BetTransferred {
+    pub event: Pubkey,
+    pub from_bet: Pubkey,
+    pub to_bet: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub outcome: Outcome,
+    pub amount: u64,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::Event;
+use crate::error::Error;
+
+pub fn update_bet_limits(
+    ctx: Context<UpdateBetLimits>,
+    min_bet: u64,
+    max_bet: u64,
+    max_wallet_exposure: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Limits can only change while betting is open
+    require!(event.betting_open, Error::BettingClosed);
+    require!(
+        Event::valid_bet_limits(min_bet, max_bet, max_wallet_exposure),
+        Error::InvalidBetLimits
+    );
+
+    event.min_bet = min_bet;
+    event.max_bet = max_bet;
+    event.max_wallet_exposure = max_wallet_exposure;
+
+    emit!(BetLimitsUpdated {
+        event: event.key(),
+        event_id: event.event_id,
+        min_bet,
+        max_bet,
+        max_wallet_exposure,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateBetLimits<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct BetLimitsUpdated {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub max_wallet_exposure: u64,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::Event;
+use crate::error::Error;
+
+pub fn update_risk_limits(
+    ctx: Context<UpdateRiskLimits>,
+    max_side_pool: u64,
+    max_total_pool: u64,
+    max_imbalance_bps: u32,
+    imbalance_min_pool: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(
+        Event::valid_risk_limits(max_side_pool, max_total_pool, max_imbalance_bps),
+        Error::InvalidRiskLimits
+    );
+
+    event.max_side_pool = max_side_pool;
+    event.max_total_pool = max_total_pool;
+    event.max_imbalance_bps = max_imbalance_bps;
+    event.imbalance_min_pool = imbalance_min_pool;
+
+    emit!(RiskLimitsUpdated {
+        event: event.key(),
+        event_id: event.event_id,
+        max_side_pool,
+        max_total_pool,
+        max_imbalance_bps,
+        imbalance_min_pool,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateRiskLimits<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct RiskLimitsUpdated {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub max_side_pool: u64,
+    pub max_total_pool: u64,
+    pub max_imbalance_bps: u32,
+    pub imbalance_min_pool: u64,
+}
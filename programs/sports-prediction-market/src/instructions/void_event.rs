@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+use crate::instructions::finalize_if_complete;
+use crate::math;
+
+/// Voids an event that will not produce a result. Every bet settles as a
+/// refund of its stake with no fee, and parlay legs on the event are dropped.
+pub fn void_event(
+    ctx: Context<VoidEvent>,
+) -> Result<()> {
+    record_void(&mut ctx.accounts.event)
+}
+
+/// Marks `event` void so every stake is owed back
+pub fn record_void(event: &mut Account<Event>) -> Result<()> {
+    require!(event.outcome == Outcome::Undrawn, Error::EventSettled);
+
+    // Share markets have no per-bet refund path; matched exchange bets unwind
+    // through refund_matched_bet and open offers through cancel_offer
+    require!(
+        matches!(event.market_type, MarketType::Parimutuel | MarketType::FixedOdds | MarketType::Exchange),
+        Error::InvalidMarketType
+    );
+
+    event.outcome = Outcome::Invalid;
+    event.betting_open = false;
+
+    if event.market_type == MarketType::FixedOdds {
+        // Only stakes are owed back; the bankroll is untouched
+        let stakes = math::total_pool(event.win_a_amount, event.win_b_amount).map_err(Error::from)?;
+        event.outstanding_payouts = u64::try_from(stakes).map_err(|_| Error::OverflowError)?;
+    }
+
+    emit!(EventVoided {
+        event: event.key(),
+        event_id: event.event_id,
+        win_a_amount: event.win_a_amount,
+        win_b_amount: event.win_b_amount,
+    });
+
+    // With no bets there is nothing to refund
+    finalize_if_complete(event)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoidEvent<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+        constraint = event.outcome == Outcome::Undrawn @ Error::EventSettled,
+        constraint = event.fixture == Pubkey::default() @ Error::FixtureMarket,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+#[event]
+pub struct EventVoided {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub win_a_amount: u128,
+    pub win_b_amount: u128,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::Tournament;
+use crate::error::Error;
+
+/// Abandons a tournament whose bracket can no longer produce a champion, for
+/// example because a match or the final was voided. Every outright stake
+/// becomes refundable through `claim_outright`.
+pub fn void_tournament(
+    ctx: Context<VoidTournament>,
+) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    tournament.voided = true;
+
+    emit!(TournamentVoided {
+        tournament: tournament.key(),
+        tournament_id: tournament.tournament_id,
+        total_pool: tournament.total_pool,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoidTournament<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = tournament.authority == authority.key() @ Error::Unauthorized,
+        constraint = tournament.champion.is_none() @ Error::EventSettled,
+        constraint = !tournament.voided @ Error::EventSettled,
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+#[event]
+pub struct TournamentVoided {
+    pub tournament: Pubkey,
+    pub tournament_id: u64,
+    pub total_pool: u64,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+use crate::instructions::BankrollUpdated;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Withdraws house funds not needed to cover the book. After the result this
+/// includes the house's winnings from losing fixed-odds bets.
+pub fn withdraw_bankroll(
+    ctx: Context<WithdrawBankroll>,
+    amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(event.market_type == MarketType::FixedOdds, Error::InvalidMarketType);
+
+    event.bankroll = event.bankroll.checked_sub(amount)
+        .ok_or(Error::BankrollExceeded)?;
+    if event.outcome == Outcome::Undrawn {
+        require!(event.book_covered()?, Error::BankrollExceeded);
+    }
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.authority_token_account.to_account_info()
+    } else {
+        ctx.accounts.authority.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(BankrollUpdated {
+        event: event.key(),
+        event_id: event.event_id,
+        bankroll: event.bankroll,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBankroll<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub authority_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::state::Event;
+use crate::error::Error;
+use crate::vault::{assert_solvent, distribute_fee, transfer_from_vault};
+
+/// Withdraws accrued fees from one or more events in a single call.
+///
+/// Remaining accounts start with the fee split recipients of `event` (if it
+/// has a split), followed by any extra events to sweep. Each extra event is
+/// followed by its token vault when the events use SPL tokens. Extra events
+/// must share the treasury, token mint and fee split of `event`, and may not
+/// repeat `event` or each other.
+pub fn withdraw_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFees<'info>>,
+) -> Result<()> {
+    let treasury = ctx.accounts.treasury.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let fee_destination = ctx.accounts.fee_destination.to_account_info();
+
+    let split_len = ctx.accounts.event.fee_split.len();
+    require!(ctx.remaining_accounts.len() >= split_len, Error::InvalidFeeRecipient);
+    let (recipients, extra_accounts) = ctx.remaining_accounts.split_at(split_len);
+
+    let mut total = withdraw_event_fees(
+        &mut ctx.accounts.event,
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &token_program,
+        &fee_destination,
+        recipients,
+        treasury,
+    )?;
+    assert_solvent(
+        &ctx.accounts.event,
+        &ctx.accounts.event.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    let stride = if ctx.accounts.event.uses_spl_token { 2 } else { 1 };
+    require!(extra_accounts.len() % stride == 0, Error::InvalidEvent);
+
+    let mut swept = vec![ctx.accounts.event.key()];
+    for accounts in extra_accounts.chunks(stride) {
+        // The primary event's zeroed fees are not written back until exit
+        require!(!swept.contains(&accounts[0].key()), Error::InvalidEvent);
+        swept.push(accounts[0].key());
+
+        let mut extra_event: Account<'info, Event> = Account::try_from(&accounts[0])?;
+        require!(extra_event.platform_fee_account == treasury, Error::Unauthorized);
+        require!(extra_event.uses_spl_token == ctx.accounts.event.uses_spl_token, Error::InvalidMint);
+        require!(extra_event.token_mint == ctx.accounts.event.token_mint, Error::InvalidMint);
+        require!(extra_event.fee_split == ctx.accounts.event.fee_split, Error::InvalidFeeSplit);
+
+        // SOL events hold fees in the event account itself
+        let extra_token_vault = accounts.get(1).unwrap_or(&accounts[0]);
+        let amount = withdraw_event_fees(
+            &mut extra_event,
+            extra_token_vault,
+            &token_program,
+            &fee_destination,
+            recipients,
+            treasury,
+        )?;
+        assert_solvent(&extra_event, &extra_event.to_account_info(), extra_token_vault)?;
+        extra_event.exit(&crate::ID)?;
+
+        total = total.checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+    }
+
+    require!(total > 0, Error::NoFeesToWithdraw);
+
+    Ok(())
+}
+
+fn withdraw_event_fees<'info>(
+    event: &mut Account<'info, Event>,
+    event_token_vault: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    fee_destination: &AccountInfo<'info>,
+    recipients: &[AccountInfo<'info>],
+    treasury: Pubkey,
+) -> Result<u64> {
+    let amount = event.accrued_fees;
+    if amount == 0 {
+        return Ok(0);
+    }
+    event.accrued_fees = 0;
+
+    let event_vault = event.to_account_info();
+    if event.fee_split.is_empty() {
+        transfer_from_vault(
+            event,
+            &event_vault,
+            event_token_vault,
+            token_program,
+            fee_destination,
+            amount,
+        )?;
+    } else {
+        distribute_fee(
+            event,
+            &event_vault,
+            event_token_vault,
+            token_program,
+            recipients,
+            amount,
+        )?;
+    }
+
+    emit!(FeesWithdrawn {
+        event: event.key(),
+        event_id: event.event_id,
+        treasury,
+        amount,
+    });
+
+    Ok(amount)
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(mut)]
+    pub treasury: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.platform_fee_account == treasury.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: Receives fees for events without a fee split (wallet for SOL,
+    /// token account for SPL). Chosen by the treasury signer.
+    #[account(mut)]
+    pub fee_destination: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{Event, MarketType, Outcome};
+use crate::error::Error;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Pays the liquidity provider for the winning shares left in the AMM pool
+/// once the result is in
+pub fn withdraw_liquidity(
+    ctx: Context<WithdrawLiquidity>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    require!(event.market_type == MarketType::Amm, Error::InvalidMarketType);
+    require!(event.outcome != Outcome::Undrawn, Error::EventNotSettled);
+
+    let amount = if event.outcome == Outcome::WinA { event.amm_reserve_a } else { event.amm_reserve_b };
+    event.amm_reserve_a = 0;
+    event.amm_reserve_b = 0;
+    event.amm_collateral = event.amm_collateral.checked_sub(amount)
+        .ok_or(Error::InvariantViolation)?;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.authority_token_account.to_account_info()
+    } else {
+        ctx.accounts.authority.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(LiquidityWithdrawn {
+        event: event.key(),
+        event_id: event.event_id,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLiquidity<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub authority_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
+
+#[event]
+pub struct LiquidityWithdrawn {
+    pub event: Pubkey,
+    pub event_id: u64,
+    pub amount: u64,
+}
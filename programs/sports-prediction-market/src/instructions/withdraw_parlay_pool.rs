@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::ParlayPool;
+use crate::error::Error;
+use crate::instructions::ParlayPoolUpdated;
+use crate::vault::assert_pool_solvent;
+
+/// Withdraws house liquidity not needed to cover open parlays
+pub fn withdraw_parlay_pool(
+    ctx: Context<WithdrawParlayPool>,
+    amount: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+
+    pool.bankroll = pool.bankroll.checked_sub(amount)
+        .ok_or(Error::InsufficientFunds)?;
+    require!(pool.covered(), Error::BankrollExceeded);
+
+    // Move lamports directly (allowed because the program owns the pool account)
+    **pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    assert_pool_solvent(pool)?;
+
+    emit!(ParlayPoolUpdated {
+        pool: pool.key(),
+        bankroll: pool.bankroll,
+        liability: pool.liability,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawParlayPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub pool: Account<'info, ParlayPool>,
+}
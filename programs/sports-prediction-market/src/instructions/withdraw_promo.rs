@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::Event;
+use crate::error::Error;
+use crate::instructions::PromoBudgetUpdated;
+use crate::vault::{assert_solvent, transfer_from_vault};
+
+/// Withdraws promo funds not reserved for bonuses. After the result this
+/// includes the bonuses reserved by losing bets.
+pub fn withdraw_promo(
+    ctx: Context<WithdrawPromo>,
+    amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    // Validate inputs
+    require!(amount > 0, Error::ZeroAmount);
+    require!(
+        amount <= event.promo_budget.saturating_sub(event.promo_owed()?),
+        Error::PromoBudgetExceeded
+    );
+
+    event.promo_budget -= amount;
+
+    let destination = if event.uses_spl_token {
+        ctx.accounts.authority_token_account.to_account_info()
+    } else {
+        ctx.accounts.authority.to_account_info()
+    };
+    transfer_from_vault(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &destination,
+        amount,
+    )?;
+
+    assert_solvent(
+        event,
+        &ctx.accounts.event_vault.to_account_info(),
+        &ctx.accounts.event_token_vault.to_account_info(),
+    )?;
+
+    emit!(PromoBudgetUpdated {
+        event: event.key(),
+        event_id: event.event_id,
+        promo_budget: event.promo_budget,
+        promo_owed: event.promo_owed()?,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawPromo<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = event.authority == authority.key() @ Error::Unauthorized,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// CHECK: This is the event PDA used as the SOL vault. It is program-owned and
+    /// constrained to equal the `event` PDA via the `constraint` below, which ensures
+    /// it matches the expected address. No further type-level checks are necessary.
+    #[account(
+        mut,
+        constraint = event_vault.key() == event.key() @ Error::InvalidEvent,
+    )]
+    pub event_vault: UncheckedAccount<'info>,
+
+    // SPL token accounts (only used if event.uses_spl_token = true)
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub authority_token_account: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    #[account(mut)]
+    pub event_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Validated in instruction logic when uses_spl_token is true
+    pub token_program: AccountInfo<'info>,
+}
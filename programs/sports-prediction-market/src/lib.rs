@@ -3,8 +3,11 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod error;
 pub mod instructions;
+pub mod vault;
+pub use sports_prediction_math as math;
+pub mod merkle;
 
-use state::Outcome;
+use state::{FeeMode, FeeShare, MarketKind, OfferSide, Outcome};
 use instructions::*;
 
 declare_id!("71MzeGyujpPthcwVQ5tC1p2eweBMbF6radaCdaJgsit9");
@@ -13,15 +16,31 @@ declare_id!("71MzeGyujpPthcwVQ5tC1p2eweBMbF6radaCdaJgsit9");
 pub mod sports_prediction_market {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_event(
         ctx: Context<CreateEvent>,
         event_id: u64,
         opponent_a: String,
         opponent_b: String,
         fee_bps: u32,
+        fee_mode: FeeMode,
         token_mint: Option<Pubkey>,
+        min_bet: u64,
+        max_bet: u64,
+        max_wallet_exposure: u64,
     ) -> Result<()> {
-        instructions::create_event(ctx, event_id, opponent_a, opponent_b, fee_bps, token_mint)
+        instructions::create_event(
+            ctx,
+            event_id,
+            opponent_a,
+            opponent_b,
+            fee_bps,
+            fee_mode,
+            token_mint,
+            min_bet,
+            max_bet,
+            max_wallet_exposure,
+        )
     }
 
     pub fn create_bet(
@@ -32,14 +51,384 @@ pub mod sports_prediction_market {
         instructions::create_bet(ctx, outcome, amount)
     }
 
+    pub fn update_bet_limits(
+        ctx: Context<UpdateBetLimits>,
+        min_bet: u64,
+        max_bet: u64,
+        max_wallet_exposure: u64,
+    ) -> Result<()> {
+        instructions::update_bet_limits(ctx, min_bet, max_bet, max_wallet_exposure)
+    }
+
+    pub fn update_risk_limits(
+        ctx: Context<UpdateRiskLimits>,
+        max_side_pool: u64,
+        max_total_pool: u64,
+        max_imbalance_bps: u32,
+        imbalance_min_pool: u64,
+    ) -> Result<()> {
+        instructions::update_risk_limits(
+            ctx,
+            max_side_pool,
+            max_total_pool,
+            max_imbalance_bps,
+            imbalance_min_pool,
+        )
+    }
+
+    pub fn set_fee_split(
+        ctx: Context<SetFeeSplit>,
+        shares: Vec<FeeShare>,
+    ) -> Result<()> {
+        instructions::set_fee_split(ctx, shares)
+    }
+
+    pub fn set_odds(
+        ctx: Context<SetOdds>,
+        odds_a_bps: u32,
+        odds_b_bps: u32,
+    ) -> Result<()> {
+        instructions::set_odds(ctx, odds_a_bps, odds_b_bps)
+    }
+
+    pub fn fund_bankroll(
+        ctx: Context<FundBankroll>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_bankroll(ctx, amount)
+    }
+
+    pub fn withdraw_bankroll(
+        ctx: Context<WithdrawBankroll>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_bankroll(ctx, amount)
+    }
+
+    pub fn seed_liquidity(
+        ctx: Context<SeedLiquidity>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::seed_liquidity(ctx, amount)
+    }
+
+    pub fn buy_shares(
+        ctx: Context<BuyShares>,
+        outcome: Outcome,
+        amount: u64,
+        min_shares_out: u64,
+    ) -> Result<()> {
+        instructions::buy_shares(ctx, outcome, amount, min_shares_out)
+    }
+
+    pub fn sell_shares(
+        ctx: Context<SellShares>,
+        outcome: Outcome,
+        shares: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::sell_shares(ctx, outcome, shares, min_amount_out)
+    }
+
+    pub fn redeem_shares(
+        ctx: Context<RedeemShares>,
+    ) -> Result<()> {
+        instructions::redeem_shares(ctx)
+    }
+
+    pub fn withdraw_liquidity(
+        ctx: Context<WithdrawLiquidity>,
+    ) -> Result<()> {
+        instructions::withdraw_liquidity(ctx)
+    }
+
+    pub fn enable_conditional_tokens(
+        ctx: Context<EnableConditionalTokens>,
+        decimals: u8,
+    ) -> Result<()> {
+        instructions::enable_conditional_tokens(ctx, decimals)
+    }
+
+    pub fn split_position(
+        ctx: Context<SplitPosition>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::split_position(ctx, amount)
+    }
+
+    pub fn merge_positions(
+        ctx: Context<MergePositions>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::merge_positions(ctx, amount)
+    }
+
+    pub fn redeem_outcome_tokens(
+        ctx: Context<RedeemOutcomeTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_outcome_tokens(ctx, amount)
+    }
+
+    pub fn post_offer(
+        ctx: Context<PostOffer>,
+        outcome: Outcome,
+        side: OfferSide,
+        odds_bps: u32,
+        stake: u64,
+    ) -> Result<()> {
+        instructions::post_offer(ctx, outcome, side, odds_bps, stake)
+    }
+
+    pub fn match_offer(
+        ctx: Context<MatchOffer>,
+        stake: u64,
+    ) -> Result<()> {
+        instructions::match_offer(ctx, stake)
+    }
+
+    pub fn cancel_offer(
+        ctx: Context<CancelOffer>,
+    ) -> Result<()> {
+        instructions::cancel_offer(ctx)
+    }
+
+    pub fn settle_matched_bet(
+        ctx: Context<SettleMatchedBet>,
+    ) -> Result<()> {
+        instructions::settle_matched_bet(ctx)
+    }
+
+    pub fn refund_matched_bet(
+        ctx: Context<RefundMatchedBet>,
+    ) -> Result<()> {
+        instructions::refund_matched_bet(ctx)
+    }
+
+    pub fn cash_out(
+        ctx: Context<CashOut>,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::cash_out(ctx, min_amount_out)
+    }
+
+    pub fn set_cash_out_fee(
+        ctx: Context<SetCashOutFee>,
+        cash_out_fee_bps: u32,
+    ) -> Result<()> {
+        instructions::set_cash_out_fee(ctx, cash_out_fee_bps)
+    }
+
+    pub fn transfer_bet(
+        ctx: Context<TransferBet>,
+    ) -> Result<()> {
+        instructions::transfer_bet(ctx)
+    }
+
+    pub fn void_event(
+        ctx: Context<VoidEvent>,
+    ) -> Result<()> {
+        instructions::void_event(ctx)
+    }
+
+    pub fn create_parlay_pool(
+        ctx: Context<CreateParlayPool>,
+    ) -> Result<()> {
+        instructions::create_parlay_pool(ctx)
+    }
+
+    pub fn fund_parlay_pool(
+        ctx: Context<FundParlayPool>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_parlay_pool(ctx, amount)
+    }
+
+    pub fn withdraw_parlay_pool(
+        ctx: Context<WithdrawParlayPool>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_parlay_pool(ctx, amount)
+    }
+
+    pub fn place_parlay<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlaceParlay<'info>>,
+        outcomes: Vec<Outcome>,
+        stake: u64,
+    ) -> Result<()> {
+        instructions::place_parlay(ctx, outcomes, stake)
+    }
+
+    pub fn settle_parlay<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleParlay<'info>>,
+    ) -> Result<()> {
+        instructions::settle_parlay(ctx)
+    }
+
+    pub fn set_market_kind(
+        ctx: Context<SetMarketKind>,
+        market_kind: MarketKind,
+    ) -> Result<()> {
+        instructions::set_market_kind(ctx, market_kind)
+    }
+
+    pub fn report_score<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReportScore<'info>>,
+        score_a: u16,
+        score_b: u16,
+    ) -> Result<()> {
+        instructions::report_score(ctx, score_a, score_b)
+    }
+
+    pub fn create_fixture(
+        ctx: Context<CreateFixture>,
+        fixture_id: u64,
+        team_a: String,
+        team_b: String,
+        league: String,
+        start_time: i64,
+    ) -> Result<()> {
+        instructions::create_fixture(ctx, fixture_id, team_a, team_b, league, start_time)
+    }
+
+    pub fn add_fixture_market(
+        ctx: Context<AddFixtureMarket>,
+    ) -> Result<()> {
+        instructions::add_fixture_market(ctx)
+    }
+
+    pub fn cancel_fixture<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelFixture<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_fixture(ctx)
+    }
+
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        tournament_id: u64,
+        name: String,
+        entrants: Vec<String>,
+        fee_bps: u32,
+    ) -> Result<()> {
+        instructions::create_tournament(ctx, tournament_id, name, entrants, fee_bps)
+    }
+
+    pub fn link_bracket_match(
+        ctx: Context<LinkBracketMatch>,
+        slot: u8,
+    ) -> Result<()> {
+        instructions::link_bracket_match(ctx, slot)
+    }
+
+    pub fn set_tournament_final(
+        ctx: Context<SetTournamentFinal>,
+    ) -> Result<()> {
+        instructions::set_tournament_final(ctx)
+    }
+
+    pub fn place_outright_bet(
+        ctx: Context<PlaceOutrightBet>,
+        entrant: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::place_outright_bet(ctx, entrant, amount)
+    }
+
+    pub fn claim_outright(
+        ctx: Context<ClaimOutright>,
+    ) -> Result<()> {
+        instructions::claim_outright(ctx)
+    }
+
+    pub fn void_tournament(
+        ctx: Context<VoidTournament>,
+    ) -> Result<()> {
+        instructions::void_tournament(ctx)
+    }
+
+    pub fn set_stake_weighting(
+        ctx: Context<SetStakeWeighting>,
+        betting_closes_at: i64,
+        decay_starts_at: i64,
+        weight_floor_bps: u32,
+    ) -> Result<()> {
+        instructions::set_stake_weighting(ctx, betting_closes_at, decay_starts_at, weight_floor_bps)
+    }
+
+    pub fn set_early_bird(
+        ctx: Context<SetEarlyBird>,
+        early_bird_cap: u64,
+        early_bird_ends_at: i64,
+        bonus_bps: u32,
+    ) -> Result<()> {
+        instructions::set_early_bird(ctx, early_bird_cap, early_bird_ends_at, bonus_bps)
+    }
+
+    pub fn fund_promo(
+        ctx: Context<FundPromo>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_promo(ctx, amount)
+    }
+
+    pub fn withdraw_promo(
+        ctx: Context<WithdrawPromo>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_promo(ctx, amount)
+    }
+
+    pub fn set_private_betting(
+        ctx: Context<SetPrivateBetting>,
+        reveal_window: i64,
+        forfeit_unrevealed: bool,
+    ) -> Result<()> {
+        instructions::set_private_betting(ctx, reveal_window, forfeit_unrevealed)
+    }
+
+    pub fn commit_bet(
+        ctx: Context<CommitBet>,
+        commitment: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        instructions::commit_bet(ctx, commitment, amount)
+    }
+
+    pub fn reveal_bet(
+        ctx: Context<RevealBet>,
+        outcome: Outcome,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_bet(ctx, outcome, salt)
+    }
+
+    pub fn expire_commitment(
+        ctx: Context<ExpireCommitment>,
+    ) -> Result<()> {
+        instructions::expire_commitment(ctx)
+    }
+
+    pub fn resolve_scalar(
+        ctx: Context<ResolveScalar>,
+        value: i64,
+    ) -> Result<()> {
+        instructions::resolve_scalar(ctx, value)
+    }
+
     pub fn close_betting(
         ctx: Context<CloseBetting>,
     ) -> Result<()> {
         instructions::close_betting(ctx)
     }
 
-    pub fn announce_winner(
-        ctx: Context<AnnounceWinner>,
+    pub fn resume_betting(
+        ctx: Context<ResumeBetting>,
+    ) -> Result<()> {
+        instructions::resume_betting(ctx)
+    }
+
+    pub fn announce_winner<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AnnounceWinner<'info>>,
         winner: Outcome,
     ) -> Result<()> {
         instructions::announce_winner(ctx, winner)
@@ -51,8 +440,52 @@ pub mod sports_prediction_market {
         instructions::settle_bet(ctx)
     }
 
-    pub fn close_event(
-        ctx: Context<CloseEvent>,
+    pub fn settle_bets_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleBetsBatch<'info>>,
+    ) -> Result<()> {
+        instructions::settle_bets_batch(ctx)
+    }
+
+    pub fn post_payout_root(
+        ctx: Context<PostPayoutRoot>,
+        payout_root: [u8; 32],
+        claim_count: u32,
+        total_payout: u64,
+    ) -> Result<()> {
+        instructions::post_payout_root(ctx, payout_root, claim_count, total_payout)
+    }
+
+    pub fn claim_with_proof(
+        ctx: Context<ClaimWithProof>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_with_proof(ctx, index, amount, proof)
+    }
+
+    pub fn withdraw_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFees<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_fees(ctx)
+    }
+
+    pub fn quote_payout(
+        ctx: Context<QuotePayout>,
+        outcome: Outcome,
+        amount: u64,
+    ) -> Result<PayoutQuote> {
+        instructions::quote_payout(ctx, outcome, amount)
+    }
+
+    pub fn quote_settlement(
+        ctx: Context<QuoteSettlement>,
+    ) -> Result<PayoutQuote> {
+        instructions::quote_settlement(ctx)
+    }
+
+    pub fn close_event<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseEvent<'info>>,
     ) -> Result<()> {
         instructions::close_event(ctx)
     }
@@ -0,0 +1,132 @@
+//! Merkle payout trees for events settled by `claim_with_proof`.
+//!
+//! Leaves commit to `(index, bettor, amount)`; the index selects the bit in
+//! the event's claim bitmap. Pairs are hashed in sorted order, so a proof is
+//! just the list of sibling hashes from leaf to root. The same code builds
+//! trees off-chain and verifies proofs on-chain.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::instructions::bet_settlement;
+use crate::state::{Bet, Event, Outcome};
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hashes a single payout leaf
+pub fn payout_leaf(index: u32, bettor: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &index.to_le_bytes(), bettor.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Returns true if `proof` links `leaf` to `root`
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
+
+/// Payout tree built off-chain by the resolver
+pub struct PayoutTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl PayoutTree {
+    /// Builds a tree whose leaf `i` pays `payouts[i]`. An odd node at the end of
+    /// a layer is carried up unchanged.
+    pub fn new(payouts: &[(Pubkey, u64)]) -> Self {
+        let leaves: Vec<[u8; 32]> = payouts
+            .iter()
+            .enumerate()
+            .map(|(index, (bettor, amount))| payout_leaf(index as u32, bettor, *amount))
+            .collect();
+
+        let mut layers = vec![leaves];
+        while layers.last().is_some_and(|layer| layer.len() > 1) {
+            let next = layers.last().unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    /// Root to post with `post_payout_root`; all zeroes for an empty tree
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last()
+            .and_then(|layer| layer.first())
+            .copied()
+            .unwrap_or([0; 32])
+    }
+
+    /// Sibling hashes proving leaf `index`
+    pub fn proof(&self, index: usize) -> Vec<[u8; 32]> {
+        let mut proof = Vec::new();
+        let mut position = index;
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(position ^ 1) {
+                proof.push(*sibling);
+            }
+            position /= 2;
+        }
+        proof
+    }
+}
+
+/// Computes each winning bettor's payout from the event's pool totals, using
+/// the same math as `settle_bet`. Losing and zero-payout bets are skipped.
+pub fn payouts_from_bets(event: &Event, bets: &[Bet]) -> Result<Vec<(Pubkey, u64)>> {
+    if event.outcome == Outcome::Undrawn {
+        return Ok(Vec::new());
+    }
+
+    let mut payouts = Vec::new();
+    for bet in bets {
+        let settlement = bet_settlement(event, bet)?;
+        if settlement.payout > 0 {
+            payouts.push((bet.authority, settlement.payout));
+        }
+    }
+    Ok(payouts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payouts(count: usize) -> Vec<(Pubkey, u64)> {
+        (0..count).map(|i| (Pubkey::new_unique(), 1_000 + i as u64)).collect()
+    }
+
+    #[test]
+    fn every_leaf_proves_against_the_root() {
+        for count in 1..=9 {
+            let payouts = payouts(count);
+            let tree = PayoutTree::new(&payouts);
+            for (index, (bettor, amount)) in payouts.iter().enumerate() {
+                let leaf = payout_leaf(index as u32, bettor, *amount);
+                assert!(verify_proof(&tree.proof(index), &tree.root(), leaf));
+            }
+        }
+    }
+
+    #[test]
+    fn tampered_leaves_do_not_verify() {
+        let payouts = payouts(5);
+        let tree = PayoutTree::new(&payouts);
+        let (bettor, amount) = payouts[2];
+        let proof = tree.proof(2);
+
+        assert!(!verify_proof(&proof, &tree.root(), payout_leaf(2, &bettor, amount + 1)));
+        assert!(!verify_proof(&proof, &tree.root(), payout_leaf(3, &bettor, amount)));
+        assert!(!verify_proof(&proof, &tree.root(), payout_leaf(2, &Pubkey::new_unique(), amount)));
+    }
+}
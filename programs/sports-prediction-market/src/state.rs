@@ -1,12 +1,44 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::error::Error;
+use crate::math;
 
 pub mod outcome;
+pub mod fee_mode;
+pub mod fee_share;
+pub mod market_type;
+pub mod market_kind;
+pub mod offer_side;
+pub mod parlay_leg;
+pub mod fixture_status;
 
 pub use outcome::Outcome;
+pub use fee_mode::FeeMode;
+pub use fee_share::{FeeShare, MAX_FEE_RECIPIENTS};
+pub use market_type::MarketType;
+pub use market_kind::MarketKind;
+pub use offer_side::OfferSide;
+pub use parlay_leg::{ParlayLeg, MAX_PARLAY_LEGS};
+pub use fixture_status::FixtureStatus;
 
-pub const EVENT_SIZE: usize = 8 + 1 + 32 + 8 + 64 + 64 + 4 + 32 + 1 + 1 + 16 + 16 + 4 + 4 + 1 + 32;
+pub const EVENT_SIZE: usize = 8 + 1 + 32 + 8 + 64 + 64 + 4 + 32 + 1 + 1 + 16 + 16 + 4 + 4 + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 4 + 8 + 1
+    + 4 + MAX_FEE_RECIPIENTS * FeeShare::SIZE + 8 + 8 + 8 + 4 + 1 + 16 + 16
+    + 1 + 32 + 4 + 8
+    + 1 + 4 + 4 + 8 + 8 + 8 + 8
+    + 8 + 8 + 8
+    + 32 + 32 + 8
+    + 8 + 8
+    + 8
+    + MarketKind::SIZE + 32 + 1 + 2 + 2
+    + 8
+    + 32 + 32 + 1
+    + 8 + 8 + 4 + 16 + 16
+    + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 8
+    + 1 + 8 + 8 + 1 + 8 + 4
+    + 4
+    + 4;
 
-pub const BET_SIZE: usize = 8 + 1 + 32 + 32 + 1 + 8 + 1;
+pub const BET_SIZE: usize = 8 + 1 + 32 + 32 + 1 + 8 + 1 + 8 + 4 + 8 + 8 + 8;
 
 #[account]
 pub struct Event {
@@ -22,7 +54,7 @@ pub struct Event {
     pub opponent_b: String,
     /// Platform fee rate in basis points (e.g., 300 = 3%)
     pub fee_bps: u32,
-    /// Platform fee collection account
+    /// Treasury wallet that collects platform fees
     pub platform_fee_account: Pubkey,
     /// Whether betting is currently open (controlled by authority)
     pub betting_open: bool,
@@ -40,10 +72,349 @@ pub struct Event {
     pub uses_spl_token: bool,
     /// Token mint address (None for SOL)
     pub token_mint: Pubkey,
+    /// Minimum amount accepted per bet
+    pub min_bet: u64,
+    /// Maximum amount accepted per bet (0 = no limit)
+    pub max_bet: u64,
+    /// Maximum total stake a single wallet can hold on this event (0 = no limit)
+    pub max_wallet_exposure: u64,
+    /// Maximum total amount on either side (0 = no cap)
+    pub max_side_pool: u64,
+    /// Maximum combined amount across both sides (0 = no cap)
+    pub max_total_pool: u64,
+    /// Larger-to-smaller side ratio in basis points that pauses betting (0 = disabled)
+    pub max_imbalance_bps: u32,
+    /// Total pool size at which the imbalance circuit breaker arms
+    pub imbalance_min_pool: u64,
+    /// How the platform fee is charged at settlement
+    pub fee_mode: FeeMode,
+    /// Revenue split of platform fees (empty = all to `platform_fee_account`)
+    pub fee_split: Vec<FeeShare>,
+    /// Fees collected at settlement and held in the vault until withdrawn
+    pub accrued_fees: u64,
+    /// Total paid out to bettors so far
+    pub total_paid_out: u64,
+    /// Total fees charged so far, including rounding dust swept at finalization
+    pub total_fees: u64,
+    /// Number of winning bets that have been settled
+    pub winners_settled: u32,
+    /// Whether every winning bet is paid and the dust has been swept
+    pub settlement_finalized: bool,
+    /// Stake still owed back to bettors (both sides before the result, winners after)
+    pub outstanding_stake: u128,
+    /// Portion of the losing pool not yet paid to winners or charged as fees
+    pub outstanding_losing_share: u128,
+    /// Whether winners claim against a posted merkle root instead of settling each bet
+    pub merkle_settlement: bool,
+    /// Merkle root of the `(index, bettor, amount)` payout leaves
+    pub payout_root: [u8; 32],
+    /// Number of leaves in the payout tree
+    pub claim_count: u32,
+    /// Payouts in the tree that have not been claimed yet
+    pub unclaimed_payouts: u64,
+    /// Pari-mutuel pool or fixed-odds book
+    pub market_type: MarketType,
+    /// Decimal odds on opponent A in basis points (25000 = 2.5), fixed-odds only
+    pub odds_a_bps: u32,
+    /// Decimal odds on opponent B in basis points, fixed-odds only
+    pub odds_b_bps: u32,
+    /// House funds in the vault backing fixed-odds payouts
+    pub bankroll: u64,
+    /// Total owed to bettors if opponent A wins, fixed-odds only
+    pub liability_a: u64,
+    /// Total owed to bettors if opponent B wins, fixed-odds only
+    pub liability_b: u64,
+    /// Fixed-odds payouts owed to winners but not yet settled
+    pub outstanding_payouts: u64,
+    /// WinA shares held by the AMM pool
+    pub amm_reserve_a: u64,
+    /// WinB shares held by the AMM pool
+    pub amm_reserve_b: u64,
+    /// Collateral backing outstanding AMM shares (one unit per complete set)
+    pub amm_collateral: u64,
+    /// Mint of the WinA outcome token, conditional-token markets only
+    pub outcome_mint_a: Pubkey,
+    /// Mint of the WinB outcome token, conditional-token markets only
+    pub outcome_mint_b: Pubkey,
+    /// Collateral backing outstanding outcome tokens (one unit per complete set)
+    pub conditional_collateral: u64,
+    /// Exchange funds held for open offers and unsettled matched bets
+    pub exchange_escrow: u64,
+    /// Number of offers posted, used to derive offer addresses
+    pub offer_count: u64,
+    /// Stake taken out of the pools by early cash-outs
+    pub total_cashed_out: u64,
+    /// What the two outcomes mean in terms of the fixture's final score
+    pub market_kind: MarketKind,
+    /// Fixture whose reported score resolves this market, or the default key
+    /// for a standalone market
+    pub fixture: Pubkey,
+    /// Whether a final score has been reported for this market
+    pub score_reported: bool,
+    /// Reported final score of opponent A
+    pub score_a: u16,
+    /// Reported final score of opponent B
+    pub score_b: u16,
+    /// Value a scalar market resolved at; the outcome records the side whose
+    /// share of the pool is larger
+    pub resolved_value: i64,
+    /// Tournament whose bracket this match belongs to, or the default key
+    pub tournament: Pubkey,
+    /// Bracket match the winner advances to, or the default key for the final
+    pub next_event: Pubkey,
+    /// Opponent slot the winner fills in the next match (0 = A, 1 = B)
+    pub next_slot: u8,
+    /// Unix timestamp after which no bets are accepted (0 = no deadline)
+    pub betting_closes_at: i64,
+    /// Unix timestamp at which new stakes start losing weight
+    pub decay_starts_at: i64,
+    /// Weight of a stake placed at `betting_closes_at`, in basis points
+    /// (0 = stakes are not weighted)
+    pub weight_floor_bps: u32,
+    /// Time-weighted stakes on WinA
+    pub weighted_a: u128,
+    /// Time-weighted stakes on WinB
+    pub weighted_b: u128,
+    /// Bonus paid to winning early-bird stakes, in basis points of the
+    /// eligible stake (0 = no promo)
+    pub early_bird_bonus_bps: u32,
+    /// The first this much stake on each side is eligible (0 = no cap-based eligibility)
+    pub early_bird_cap: u64,
+    /// Stakes placed before this unix timestamp are eligible in full (0 = none)
+    pub early_bird_ends_at: i64,
+    /// Eligible stake counted on WinA
+    pub early_bird_a: u64,
+    /// Eligible stake counted on WinB
+    pub early_bird_b: u64,
+    /// Promo funds deposited by the authority and not yet paid out or withdrawn
+    pub promo_budget: u64,
+    /// Bonuses reserved for WinA bets, paid out of the promo budget if WinA wins
+    pub promo_reserved_a: u64,
+    /// Bonuses reserved for WinB bets, paid out of the promo budget if WinB wins
+    pub promo_reserved_b: u64,
+    /// Whether bets are committed blind and revealed after betting closes
+    pub private_betting: bool,
+    /// Length of the reveal window that opens when betting closes, in seconds
+    pub reveal_window: i64,
+    /// Unix timestamp at which the reveal window ends (0 = not opened yet)
+    pub reveal_ends_at: i64,
+    /// Whether unrevealed stakes are forfeited to the treasury instead of refunded
+    pub forfeit_unrevealed: bool,
+    /// Stakes escrowed by commitments that have not been revealed or expired
+    pub committed_amount: u64,
+    /// Number of commitments made
+    pub commit_count: u32,
+    /// Fee charged on the value of an early cash-out, in basis points
+    pub cash_out_fee_bps: u32,
+    /// Legs of unsettled parlays placed on this event; it cannot close until they settle
+    pub open_parlay_legs: u32,
 }
 
 impl Event {
     pub const SPACE: usize = EVENT_SIZE;
+
+    /// Returns true if the given limits are internally consistent
+    pub fn valid_bet_limits(min_bet: u64, max_bet: u64, max_wallet_exposure: u64) -> bool {
+        (max_bet == 0 || min_bet <= max_bet)
+            && (max_wallet_exposure == 0 || min_bet <= max_wallet_exposure)
+    }
+
+    /// Everything the vault still owes: unsettled stake, the undistributed
+    /// share of the losing pool, unclaimed merkle payouts, and fees awaiting
+    /// withdrawal
+    pub fn liabilities(&self) -> Result<u128> {
+        let owed_to_bettors = match self.market_type {
+            MarketType::Parimutuel => self.outstanding_stake.checked_add(self.outstanding_losing_share),
+            // Before the result the book must cover whichever side wins
+            MarketType::FixedOdds if self.outcome == Outcome::Undrawn => {
+                Some(self.liability_a.max(self.liability_b) as u128)
+            }
+            MarketType::FixedOdds => Some(self.outstanding_payouts as u128),
+            // Every share of the eventual winner redeems for one unit
+            MarketType::Amm => Some(self.amm_collateral as u128),
+            MarketType::ConditionalTokens => Some(self.conditional_collateral as u128),
+            MarketType::Exchange => Some(self.exchange_escrow as u128),
+        };
+        Ok(owed_to_bettors
+            .and_then(|total| total.checked_add(self.unclaimed_payouts as u128))
+            .and_then(|total| total.checked_add(self.accrued_fees as u128))
+            .and_then(|total| total.checked_add(self.promo_budget as u128))
+            .and_then(|total| total.checked_add(self.committed_amount as u128))
+            .ok_or(Error::OverflowError)?)
+    }
+
+    /// Promo bonuses that may still have to be paid: every reservation before
+    /// the result, then only the winning side's
+    pub fn promo_owed(&self) -> Result<u64> {
+        Ok(match self.outcome {
+            Outcome::Undrawn => self.promo_reserved_a.checked_add(self.promo_reserved_b)
+                .ok_or(Error::OverflowError)?,
+            Outcome::WinA => self.promo_reserved_a,
+            Outcome::WinB => self.promo_reserved_b,
+            Outcome::Invalid => 0,
+        })
+    }
+
+    /// Part of a new `amount` on `outcome` placed at `now` that qualifies for
+    /// the early-bird bonus, and the bonus it reserves out of whatever promo
+    /// budget is not reserved yet
+    pub fn early_bird_reservation(&self, outcome: Outcome, amount: u64, now: i64) -> Result<(u64, u64)> {
+        if self.early_bird_bonus_bps == 0
+            || self.market_type != MarketType::Parimutuel
+            || self.market_kind.is_scalar()
+        {
+            return Ok((0, 0));
+        }
+
+        let counted = if outcome == Outcome::WinA { self.early_bird_a } else { self.early_bird_b };
+        let eligible = if now < self.early_bird_ends_at {
+            amount
+        } else {
+            amount.min(self.early_bird_cap.saturating_sub(counted))
+        };
+        let bonus = math::apply_bps(eligible as u128, self.early_bird_bonus_bps, math::Rounding::Down)
+            .map_err(Error::from)?;
+        let bonus = u64::try_from(bonus).map_err(|_| Error::OverflowError)?;
+        let unreserved = self.promo_budget.checked_sub(self.promo_owed()?)
+            .ok_or(Error::InvariantViolation)?;
+
+        Ok((eligible, bonus.min(unreserved)))
+    }
+
+    /// Weight of a new `amount` placed at `now`; late stakes earn a smaller
+    /// share of the losing pool on weighted events
+    pub fn stake_weight(&self, amount: u64, now: i64) -> Result<u64> {
+        if !self.stake_weighted() {
+            return Ok(amount);
+        }
+        let weight_bps = math::stake_weight_bps(
+            now,
+            self.decay_starts_at,
+            self.betting_closes_at,
+            self.weight_floor_bps,
+        );
+        let weight = math::apply_bps(amount as u128, weight_bps, math::Rounding::Up)
+            .map_err(Error::from)?;
+        Ok(u64::try_from(weight).map_err(|_| Error::OverflowError)?)
+    }
+
+    /// Returns true if a private event's pools may still change through
+    /// reveals, so it cannot be resolved yet
+    pub fn reveal_pending(&self, now: i64) -> bool {
+        self.private_betting && (self.reveal_ends_at == 0 || now < self.reveal_ends_at)
+    }
+
+    /// Returns true if winners split the losing pool by time-weighted stake
+    pub fn stake_weighted(&self) -> bool {
+        self.weight_floor_bps > 0
+    }
+
+    /// Returns true if this is a pari-mutuel pool with no bets yet that can
+    /// still become `market_type`. Scalar pools must stay pari-mutuel, and
+    /// fixture markets may only become fixed-odds books so that
+    /// `cancel_fixture` can still void them.
+    pub fn can_change_market_type(&self, market_type: MarketType) -> bool {
+        self.market_type == MarketType::Parimutuel
+            && !self.private_betting
+            && self.win_a_count == 0
+            && self.win_b_count == 0
+            && !self.market_kind.is_scalar()
+            && (self.fixture == Pubkey::default() || market_type == MarketType::FixedOdds)
+    }
+
+    /// Returns true if every stake is owed back: the event is void, a
+    /// pari-mutuel winner was announced with nothing staked on it, or a
+    /// scalar market resolved with one side empty, leaving its pot unclaimable
+    pub fn refunds_all_stakes(&self) -> bool {
+        let winning_pool = match self.outcome {
+            Outcome::Invalid => return true,
+            Outcome::Undrawn => return false,
+            Outcome::WinA => self.win_a_amount,
+            Outcome::WinB => self.win_b_amount,
+        };
+        if self.market_type != MarketType::Parimutuel {
+            return false;
+        }
+        if self.market_kind.is_scalar() {
+            return self.win_a_amount == 0 || self.win_b_amount == 0;
+        }
+        winning_pool == 0
+    }
+
+    /// Number of bets on the announced winning side, or every bet once all
+    /// stakes are refunded or a scalar market splits the pool
+    pub fn winning_count(&self) -> u32 {
+        if self.refunds_all_stakes() || (self.market_kind.is_scalar() && self.outcome != Outcome::Undrawn) {
+            return self.win_a_count + self.win_b_count;
+        }
+        match self.outcome {
+            Outcome::WinA => self.win_a_count,
+            Outcome::WinB => self.win_b_count,
+            Outcome::Invalid | Outcome::Undrawn => 0,
+        }
+    }
+
+    /// Returns true if the bankroll plus all stakes covers the payout owed on
+    /// either outcome
+    pub fn book_covered(&self) -> Result<bool> {
+        let capacity = math::total_pool(self.win_a_amount, self.win_b_amount)
+            .map_err(Error::from)?
+            .checked_add(self.bankroll as u128)
+            .ok_or(Error::OverflowError)?;
+        Ok(self.liability_a.max(self.liability_b) as u128 <= capacity)
+    }
+
+    /// Once every winning bet has been settled, assigns the rounding dust left
+    /// in the pool to the treasury. The dust implied by the payout and fee
+    /// totals must match what the per-bet stake and losing-share counters
+    /// still hold. Returns the dust swept, or None if settlement is still in
+    /// progress.
+    pub fn finalize_settlement(&mut self) -> Result<Option<u64>> {
+        if self.settlement_finalized
+            || matches!(self.market_type, MarketType::Amm | MarketType::ConditionalTokens | MarketType::Exchange)
+            || self.outcome == Outcome::Undrawn
+            || self.winners_settled < self.winning_count()
+        {
+            return Ok(None);
+        }
+
+        // The house keeps or covers the difference on a fixed-odds book; there is no dust
+        if self.market_type == MarketType::FixedOdds {
+            self.outstanding_payouts = 0;
+            self.settlement_finalized = true;
+            return Ok(Some(0));
+        }
+
+        // Cash-outs are paid and charged out of stake that has since left the pools
+        let total_pool = math::total_pool(self.win_a_amount, self.win_b_amount)
+            .map_err(Error::from)?
+            .checked_add(self.total_cashed_out as u128)
+            .ok_or(Error::OverflowError)?;
+        let distributed = (self.total_paid_out as u128).checked_add(self.total_fees as u128)
+            .ok_or(Error::OverflowError)?;
+        let dust = total_pool.checked_sub(distributed)
+            .ok_or(Error::InvariantViolation)?;
+        let undistributed = self.outstanding_stake.checked_add(self.outstanding_losing_share)
+            .ok_or(Error::OverflowError)?;
+        require!(dust == undistributed, Error::InvariantViolation);
+        let dust = u64::try_from(dust).map_err(|_| Error::OverflowError)?;
+
+        self.total_fees = self.total_fees.checked_add(dust)
+            .ok_or(Error::OverflowError)?;
+        self.accrued_fees = self.accrued_fees.checked_add(dust)
+            .ok_or(Error::OverflowError)?;
+        self.outstanding_stake = 0;
+        self.outstanding_losing_share = 0;
+        self.settlement_finalized = true;
+
+        Ok(Some(dust))
+    }
+
+    /// Returns true if the given risk limits are internally consistent
+    pub fn valid_risk_limits(max_side_pool: u64, max_total_pool: u64, max_imbalance_bps: u32) -> bool {
+        (max_total_pool == 0 || max_side_pool <= max_total_pool)
+            && (max_imbalance_bps == 0 || max_imbalance_bps >= 10000)
+    }
 }
 
 #[account]
@@ -56,12 +427,318 @@ pub struct Bet {
     pub event: Pubkey,
     /// Outcome bet on
     pub outcome: Outcome,
-    /// Amount bet
+    /// Total amount staked by this wallet
     pub amount: u64,
     /// Whether this bet has been settled
     pub settled: bool,
+    /// Payout locked in at the posted odds, fixed-odds only
+    pub potential_payout: u64,
+    /// Odds in basis points on the most recent stake, fixed-odds only
+    pub odds_bps: u32,
+    /// Stake scaled by the weighting curve at the time each part was placed
+    pub weighted_amount: u64,
+    /// Part of the stake that qualified for the early-bird bonus
+    pub early_bird_amount: u64,
+    /// Early-bird bonus reserved for this bet, paid on top of its winnings
+    pub early_bird_bonus: u64,
 }
 
 impl Bet {
     pub const SPACE: usize = BET_SIZE;
 }
+
+pub const POSITION_SIZE: usize = 8 + 1 + 32 + 32 + 8 + 8;
+
+#[account]
+pub struct Position {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Wallet that owns the shares
+    pub owner: Pubkey,
+    /// AMM event the shares are for
+    pub event: Pubkey,
+    /// WinA shares held
+    pub shares_a: u64,
+    /// WinB shares held
+    pub shares_b: u64,
+}
+
+impl Position {
+    pub const SPACE: usize = POSITION_SIZE;
+
+    pub fn shares_mut(&mut self, outcome: Outcome) -> &mut u64 {
+        if outcome == Outcome::WinA { &mut self.shares_a } else { &mut self.shares_b }
+    }
+}
+
+pub const OFFER_SIZE: usize = 8 + 1 + 32 + 32 + 8 + 1 + 1 + 4 + 8 + 8 + 4;
+
+#[account]
+pub struct Offer {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Exchange event the offer is for
+    pub event: Pubkey,
+    /// Wallet that posted the offer
+    pub maker: Pubkey,
+    /// Sequence number of the offer on its event
+    pub offer_id: u64,
+    /// Outcome being backed or laid
+    pub outcome: Outcome,
+    /// Whether the maker backs or lays the outcome
+    pub side: OfferSide,
+    /// Decimal odds in basis points (25000 = 2.5)
+    pub odds_bps: u32,
+    /// Backer stake still open to be matched
+    pub stake_remaining: u64,
+    /// Maker funds escrowed for the unmatched remainder
+    pub escrow: u64,
+    /// Number of matches against this offer
+    pub fill_count: u32,
+}
+
+impl Offer {
+    pub const SPACE: usize = OFFER_SIZE;
+}
+
+pub const MATCHED_BET_SIZE: usize = 8 + 1 + 32 + 32 + 32 + 32 + 1 + 4 + 8 + 8;
+
+#[account]
+pub struct MatchedBet {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Exchange event the bet is on
+    pub event: Pubkey,
+    /// Wallet backing the outcome
+    pub backer: Pubkey,
+    /// Wallet laying the outcome
+    pub layer: Pubkey,
+    /// Wallet that matched the offer and paid for this account
+    pub taker: Pubkey,
+    /// Outcome the backer wins on
+    pub outcome: Outcome,
+    /// Decimal odds the bet was matched at, in basis points
+    pub odds_bps: u32,
+    /// Stake escrowed by the backer
+    pub back_stake: u64,
+    /// Liability escrowed by the layer
+    pub lay_liability: u64,
+}
+
+impl MatchedBet {
+    pub const SPACE: usize = MATCHED_BET_SIZE;
+}
+
+pub const PARLAY_POOL_SIZE: usize = 8 + 1 + 32 + 8 + 8 + 8 + 8;
+
+/// House pool that prices and backs parlays. Parlays span several event
+/// vaults, so stakes and payouts go through this pool, which holds SOL.
+#[account]
+pub struct ParlayPool {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Wallet that funds the pool and may withdraw its free bankroll
+    pub authority: Pubkey,
+    /// House funds, excluding the stakes of open parlays
+    pub bankroll: u64,
+    /// Stakes escrowed by parlays that have not been settled
+    pub open_stake: u64,
+    /// Largest total the pool could owe if every open parlay wins
+    pub liability: u64,
+    /// Number of parlays placed, used to derive parlay addresses
+    pub parlay_count: u64,
+}
+
+impl ParlayPool {
+    pub const SPACE: usize = PARLAY_POOL_SIZE;
+
+    /// Returns true if the bankroll plus open stakes covers every open parlay winning
+    pub fn covered(&self) -> bool {
+        self.liability as u128 <= self.bankroll as u128 + self.open_stake as u128
+    }
+}
+
+#[account]
+pub struct Parlay {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Pool that backs the parlay
+    pub pool: Pubkey,
+    /// Wallet that placed the parlay
+    pub owner: Pubkey,
+    /// Sequence number of the parlay on its pool
+    pub parlay_id: u64,
+    /// Stake escrowed in the pool
+    pub stake: u64,
+    /// Payout if every leg wins
+    pub potential_payout: u64,
+    /// Picks, each at the odds locked in when the parlay was placed
+    pub legs: Vec<ParlayLeg>,
+}
+
+impl Parlay {
+    /// Account size for a parlay with `leg_count` legs
+    pub fn space(leg_count: usize) -> usize {
+        8 + 1 + 32 + 32 + 8 + 8 + 8 + 4 + leg_count * ParlayLeg::SIZE
+    }
+}
+
+pub const FIXTURE_SIZE: usize = 8 + 1 + 32 + 8 + 4 + 32 + 4 + 32 + 4 + 32 + 8 + 1 + 2 + 2 + 2;
+
+/// A single match that owns several child markets, such as the moneyline,
+/// handicap and total lines on it. One score report resolves them all.
+#[account]
+pub struct Fixture {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Wallet that manages the fixture and its markets
+    pub authority: Pubkey,
+    /// Unique identifier used to derive the fixture address
+    pub fixture_id: u64,
+    /// Name of the first team
+    pub team_a: String,
+    /// Name of the second team
+    pub team_b: String,
+    /// Competition the fixture belongs to
+    pub league: String,
+    /// Scheduled kick-off as a unix timestamp
+    pub start_time: i64,
+    /// Whether the fixture is still open, completed or cancelled
+    pub status: FixtureStatus,
+    /// Number of child markets attached to the fixture
+    pub market_count: u16,
+    /// Reported final score of team A
+    pub score_a: u16,
+    /// Reported final score of team B
+    pub score_b: u16,
+}
+
+impl Fixture {
+    pub const SPACE: usize = FIXTURE_SIZE;
+}
+
+pub const BET_COMMITMENT_SIZE: usize = 8 + 1 + 32 + 32 + 32 + 8;
+
+/// A private bet's escrowed stake and the hash that hides its side until reveal
+#[account]
+pub struct BetCommitment {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Event the bet is for
+    pub event: Pubkey,
+    /// Wallet that committed the bet
+    pub authority: Pubkey,
+    /// Hash binding the bet to its outcome and a secret salt
+    pub commitment: [u8; 32],
+    /// Stake escrowed in the event vault
+    pub amount: u64,
+}
+
+impl BetCommitment {
+    pub const SPACE: usize = BET_COMMITMENT_SIZE;
+
+    /// Commitment to betting `outcome` on `event` from `authority`: the hash
+    /// of the event, the wallet, the outcome's one-byte encoding and the salt
+    pub fn hash(event: &Pubkey, authority: &Pubkey, outcome: Outcome, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[event.as_ref(), authority.as_ref(), &[outcome as u8], salt]).to_bytes()
+    }
+}
+
+/// Largest number of entrants in a tournament
+pub const MAX_TOURNAMENT_ENTRANTS: usize = 64;
+
+/// A knockout bracket or fight card. Each match is an `Event` linked to the
+/// match its winner advances to; the final decides the champion, which
+/// resolves the outright pool held by this account in SOL.
+#[account]
+pub struct Tournament {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Wallet that builds the bracket and announces results
+    pub authority: Pubkey,
+    /// Unique identifier used to derive the tournament address
+    pub tournament_id: u64,
+    /// Display name of the tournament
+    pub name: String,
+    /// Treasury wallet that receives outright fees
+    pub platform_fee_account: Pubkey,
+    /// Fee on outright winnings, in basis points
+    pub fee_bps: u32,
+    /// Bracket match that decides the champion
+    pub final_event: Pubkey,
+    /// Entrant names; bracket matches must use the same names
+    pub entrants: Vec<String>,
+    /// Outright stakes on each entrant, in entrant order
+    pub entrant_pools: Vec<u64>,
+    /// Sum of all outright stakes
+    pub total_pool: u64,
+    /// Index of the winning entrant once the final is announced
+    pub champion: Option<u8>,
+    /// Whether the tournament was abandoned and every outright stake is refunded
+    pub voided: bool,
+}
+
+impl Tournament {
+    /// Account size for a tournament with `entrant_count` entrants
+    pub fn space(entrant_count: usize) -> usize {
+        8 + 1 + 32 + 8 + 4 + 32 + 32 + 4 + 32 + 4 + entrant_count * (4 + 32) + 4 + entrant_count * 8 + 8 + 2 + 1
+    }
+
+    /// Index of the entrant called `name`
+    pub fn entrant_index(&self, name: &str) -> Option<u8> {
+        self.entrants.iter().position(|entrant| entrant == name).map(|index| index as u8)
+    }
+}
+
+pub const OUTRIGHT_BET_SIZE: usize = 8 + 1 + 32 + 32 + 1 + 8;
+
+/// A wallet's outright stake on one tournament entrant
+#[account]
+pub struct OutrightBet {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Tournament the bet is on
+    pub tournament: Pubkey,
+    /// Wallet that placed the bet
+    pub authority: Pubkey,
+    /// Index of the backed entrant
+    pub entrant: u8,
+    /// Total amount staked
+    pub amount: u64,
+}
+
+impl OutrightBet {
+    pub const SPACE: usize = OUTRIGHT_BET_SIZE;
+}
+
+/// Largest payout tree whose claim bitmap fits in a single account allocation
+pub const MAX_MERKLE_CLAIMS: u32 = 80_000;
+
+#[account]
+pub struct ClaimBitmap {
+    /// Bump seed used to generate the program address
+    pub bump: [u8; 1],
+    /// Event whose merkle payouts this bitmap tracks
+    pub event: Pubkey,
+    /// One bit per payout leaf, set once claimed
+    pub bits: Vec<u8>,
+}
+
+impl ClaimBitmap {
+    /// Account size for a tree with `claim_count` leaves
+    pub fn space(claim_count: u32) -> usize {
+        8 + 1 + 32 + 4 + Self::byte_len(claim_count)
+    }
+
+    pub fn byte_len(claim_count: u32) -> usize {
+        (claim_count as usize).div_ceil(8)
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.bits[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.bits[index as usize / 8] |= 1 << (index % 8);
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug
+)]
+pub enum FeeMode {
+    /// Fee is taken from the winning stake
+    Stake,
+    /// Fee is taken from the net profit only (exchange-style commission)
+    NetWinnings,
+    /// Fee is taken from the total pool before distribution (tote-style)
+    PoolTake,
+}
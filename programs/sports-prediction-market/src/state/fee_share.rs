@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::error::Error;
+use crate::math::{self, Rounding};
+
+/// Maximum number of recipients in an event's fee split
+pub const MAX_FEE_RECIPIENTS: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct FeeShare {
+    /// Wallet that receives this share (token account owner for SPL events)
+    pub recipient: Pubkey,
+    /// Share of the fee in basis points
+    pub share_bps: u16,
+}
+
+impl FeeShare {
+    pub const SIZE: usize = 32 + 2;
+
+    /// Returns true if the table has at most `MAX_FEE_RECIPIENTS` non-zero
+    /// shares summing to exactly 10000 bps
+    pub fn valid_split(shares: &[FeeShare]) -> bool {
+        shares.len() <= MAX_FEE_RECIPIENTS
+            && shares.iter().all(|share| share.share_bps > 0)
+            && shares.iter().map(|share| share.share_bps as u32).sum::<u32>() == 10000
+    }
+
+    /// Splits `amount` by share, rounding each part down. The rounding
+    /// remainder goes to the first recipient so the parts always sum to `amount`.
+    pub fn split(amount: u64, shares: &[FeeShare]) -> Result<Vec<u64>> {
+        let mut parts = Vec::with_capacity(shares.len());
+        for share in shares {
+            let part = math::apply_bps(amount as u128, share.share_bps as u32, Rounding::Down)
+                .map_err(Error::from)?;
+            parts.push(u64::try_from(part).map_err(|_| Error::OverflowError)?);
+        }
+        let distributed = parts.iter().try_fold(0u64, |sum, part| sum.checked_add(*part))
+            .ok_or(Error::OverflowError)?;
+        if let Some(first) = parts.first_mut() {
+            *first += amount.checked_sub(distributed).ok_or(Error::OverflowError)?;
+        }
+        Ok(parts)
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug
+)]
+pub enum FixtureStatus {
+    /// Markets may still be added; awaiting the final score
+    Scheduled,
+    /// The final score was reported and every child market resolved
+    Completed,
+    /// The fixture will not be played; every child market was voided
+    Cancelled,
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use super::Outcome;
+
+/// What an event's two outcomes mean, given the fixture's final score.
+/// Lines are in half points so that half-point lines never push; a whole-point
+/// line landing exactly on the score pushes and voids the market.
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug
+)]
+pub enum MarketKind {
+    /// WinA if opponent A outscores B, WinB if B outscores A; a draw is void
+    Moneyline,
+    /// WinA if opponent A covers: A's score plus the line beats B's score
+    Handicap { line_halves: i32 },
+    /// WinA (over) if the combined score beats the line, WinB (under) if not
+    Total { line_halves: u32 },
+    /// WinA if the final score is exactly this, WinB otherwise
+    ExactScore { score_a: u16, score_b: u16 },
+    /// WinA is long and WinB short a value on `[min, max]`. The resolved value
+    /// splits the whole pool linearly: the long side's share grows from zero at
+    /// `min` to everything at `max`.
+    Scalar { min: i64, max: i64 },
+}
+
+impl MarketKind {
+    pub const SIZE: usize = 1 + 8 + 8;
+
+    pub fn is_scalar(&self) -> bool {
+        matches!(self, MarketKind::Scalar { .. })
+    }
+
+    /// Outcome of this market for a final score of `score_a`-`score_b`:
+    /// WinA, WinB, or Invalid for a push. None for scalar markets, which
+    /// resolve from a reported value instead.
+    pub fn resolve(&self, score_a: u16, score_b: u16) -> Option<Outcome> {
+        let (a, b) = (score_a as i64, score_b as i64);
+        let (left, right) = match *self {
+            MarketKind::Moneyline => (a, b),
+            MarketKind::Handicap { line_halves } => (2 * a + line_halves as i64, 2 * b),
+            MarketKind::Total { line_halves } => (2 * (a + b), line_halves as i64),
+            MarketKind::ExactScore { score_a: exact_a, score_b: exact_b } => {
+                let hit = (score_a, score_b) == (exact_a, exact_b);
+                return Some(if hit { Outcome::WinA } else { Outcome::WinB });
+            }
+            MarketKind::Scalar { .. } => return None,
+        };
+        Some(match left.cmp(&right) {
+            std::cmp::Ordering::Greater => Outcome::WinA,
+            std::cmp::Ordering::Less => Outcome::WinB,
+            std::cmp::Ordering::Equal => Outcome::Invalid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moneyline_draw_is_void() {
+        assert_eq!(MarketKind::Moneyline.resolve(2, 1), Some(Outcome::WinA));
+        assert_eq!(MarketKind::Moneyline.resolve(0, 3), Some(Outcome::WinB));
+        assert_eq!(MarketKind::Moneyline.resolve(1, 1), Some(Outcome::Invalid));
+    }
+
+    #[test]
+    fn handicap_applies_the_line_to_opponent_a() {
+        // A -1.5: must win by two
+        let minus_one_and_a_half = MarketKind::Handicap { line_halves: -3 };
+        assert_eq!(minus_one_and_a_half.resolve(3, 1), Some(Outcome::WinA));
+        assert_eq!(minus_one_and_a_half.resolve(2, 1), Some(Outcome::WinB));
+        // A +1: a one-goal loss pushes
+        let plus_one = MarketKind::Handicap { line_halves: 2 };
+        assert_eq!(plus_one.resolve(1, 1), Some(Outcome::WinA));
+        assert_eq!(plus_one.resolve(0, 1), Some(Outcome::Invalid));
+        assert_eq!(plus_one.resolve(0, 2), Some(Outcome::WinB));
+    }
+
+    #[test]
+    fn total_compares_the_combined_score() {
+        let two_and_a_half = MarketKind::Total { line_halves: 5 };
+        assert_eq!(two_and_a_half.resolve(2, 1), Some(Outcome::WinA));
+        assert_eq!(two_and_a_half.resolve(1, 1), Some(Outcome::WinB));
+        assert_eq!(MarketKind::Total { line_halves: 6 }.resolve(2, 1), Some(Outcome::Invalid));
+    }
+
+    #[test]
+    fn exact_score_never_pushes() {
+        let two_one = MarketKind::ExactScore { score_a: 2, score_b: 1 };
+        assert_eq!(two_one.resolve(2, 1), Some(Outcome::WinA));
+        assert_eq!(two_one.resolve(1, 2), Some(Outcome::WinB));
+    }
+
+    #[test]
+    fn scalar_does_not_resolve_from_a_score() {
+        assert_eq!(MarketKind::Scalar { min: 0, max: 10 }.resolve(2, 1), None);
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug
+)]
+pub enum MarketType {
+    /// Winners split the losing pool pro rata
+    Parimutuel,
+    /// Bets lock in the authority's posted odds and a house bankroll backs the
+    /// payouts. The house margin is priced into the odds, so no platform fee applies.
+    FixedOdds,
+    /// Users trade WinA/WinB shares against a constant-product pool seeded by
+    /// the authority; each winning share redeems for one unit of collateral
+    Amm,
+    /// Collateral is split into WinA and WinB SPL tokens that trade freely;
+    /// winning tokens redeem 1:1 after the result
+    ConditionalTokens,
+    /// Users post back and lay offers at their own odds and match each other;
+    /// each matched bet escrows both sides, and the platform takes `fee_bps`
+    /// commission on the winner's net winnings
+    Exchange,
+}
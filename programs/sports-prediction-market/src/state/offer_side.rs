@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Debug
+)]
+pub enum OfferSide {
+    /// Backs the outcome to happen; escrows the stake
+    Back,
+    /// Lays the outcome against a backer; escrows the backer's winnings
+    Lay,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::error::Error;
+use crate::math::{self, Rounding};
+use super::Outcome;
+
+/// Maximum number of legs on a single parlay
+pub const MAX_PARLAY_LEGS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct ParlayLeg {
+    /// Event this leg is on
+    pub event: Pubkey,
+    /// Outcome picked on the event
+    pub outcome: Outcome,
+    /// Decimal odds locked in for the leg, in basis points
+    pub odds_bps: u32,
+}
+
+impl ParlayLeg {
+    pub const SIZE: usize = 32 + 1 + 4;
+
+    /// Multiplies leg odds into combined decimal odds in basis points, rounding
+    /// down after each leg. No legs gives even money (10000).
+    pub fn combined_odds_bps(odds: impl IntoIterator<Item = u32>) -> Result<u128> {
+        let mut combined = math::BPS_DENOMINATOR;
+        for leg_bps in odds {
+            combined = math::apply_bps(combined, leg_bps, Rounding::Down).map_err(Error::from)?;
+        }
+        Ok(combined)
+    }
+}
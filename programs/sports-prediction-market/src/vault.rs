@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+use crate::state::{Event, FeeShare, ParlayPool};
+use crate::error::Error;
+
+/// Pays `amount` out of the event vault: SPL tokens from the event token vault
+/// (signed by the event PDA), or lamports directly from the event PDA.
+pub fn transfer_from_vault<'info>(
+    event: &Account<'info, Event>,
+    event_vault: &AccountInfo<'info>,
+    event_token_vault: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if event.uses_spl_token {
+        // The event PDA signs this transfer, so only the real token program may receive it
+        require_keys_eq!(token_program.key(), token::ID, Error::InvalidTokenAccount);
+
+        let event_id_bytes = event.event_id.to_le_bytes();
+        let seeds = &[
+            b"event",
+            event_id_bytes.as_ref(),
+            &[event.bump[0]],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: event_token_vault.clone(),
+            to: destination.clone(),
+            authority: event.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.clone(),
+            cpi_accounts,
+            signer,
+        );
+        token::transfer(cpi_ctx, amount)?;
+    } else {
+        // Ensure vault has enough lamports
+        require!(event_vault.lamports() >= amount, Error::InsufficientFunds);
+
+        // Move lamports directly (allowed because the program owns the event account)
+        **event_vault.try_borrow_mut_lamports()? -= amount;
+        **destination.try_borrow_mut_lamports()? += amount;
+    }
+
+    Ok(())
+}
+
+/// Moves `amount` from a user into the event vault: SPL tokens from the
+/// user's token account into the event token vault, or lamports into the event PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_to_vault<'info>(
+    event: &Account<'info, Event>,
+    user: &AccountInfo<'info>,
+    user_token_account: &AccountInfo<'info>,
+    event_vault: &AccountInfo<'info>,
+    event_token_vault: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if event.uses_spl_token {
+        require_keys_eq!(token_program.key(), token::ID, Error::InvalidTokenAccount);
+        let cpi_accounts = Transfer {
+            from: user_token_account.clone(),
+            to: event_token_vault.clone(),
+            authority: user.clone(),
+        };
+        token::transfer(CpiContext::new(token_program.clone(), cpi_accounts), amount)
+    } else {
+        let cpi_accounts = anchor_lang::system_program::Transfer {
+            from: user.clone(),
+            to: event_vault.clone(),
+        };
+        anchor_lang::system_program::transfer(CpiContext::new(system_program.clone(), cpi_accounts), amount)
+    }
+}
+
+/// Pays a fee out of the vault according to the event's fee split.
+/// `recipients` must list one account per share, in table order: the
+/// recipient wallet for SOL events, or a token account it owns for SPL events.
+pub fn distribute_fee<'info>(
+    event: &Account<'info, Event>,
+    event_vault: &AccountInfo<'info>,
+    event_token_vault: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    recipients: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    require!(recipients.len() == event.fee_split.len(), Error::InvalidFeeRecipient);
+
+    let parts = FeeShare::split(amount, &event.fee_split)?;
+    for ((share, part), recipient) in event.fee_split.iter().zip(parts).zip(recipients) {
+        if event.uses_spl_token {
+            let token_account = TokenAccount::try_deserialize(&mut &recipient.try_borrow_data()?[..])
+                .map_err(|_| Error::InvalidFeeRecipient)?;
+            require!(token_account.owner == share.recipient, Error::InvalidFeeRecipient);
+            require!(token_account.mint == event.token_mint, Error::InvalidMint);
+        } else {
+            require!(recipient.key() == share.recipient, Error::InvalidFeeRecipient);
+        }
+
+        transfer_from_vault(
+            event,
+            event_vault,
+            event_token_vault,
+            token_program,
+            recipient,
+            part,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Fails with `InsufficientFunds` unless the vault balance still covers
+/// everything the event owes. SOL vaults exclude the event account's rent reserve.
+pub fn assert_solvent<'info>(
+    event: &Account<'info, Event>,
+    event_vault: &AccountInfo<'info>,
+    event_token_vault: &AccountInfo<'info>,
+) -> Result<()> {
+    let available = if event.uses_spl_token {
+        let token_account = TokenAccount::try_deserialize(&mut &event_token_vault.try_borrow_data()?[..])
+            .map_err(|_| Error::InvalidTokenAccount)?;
+        require_keys_eq!(token_account.owner, event.key(), Error::InvalidTokenAccount);
+        require_keys_eq!(token_account.mint, event.token_mint, Error::InvalidMint);
+        token_account.amount
+    } else {
+        let rent = Rent::get()?.minimum_balance(event_vault.data_len());
+        event_vault.lamports().saturating_sub(rent)
+    };
+
+    require!(available as u128 >= event.liabilities()?, Error::InsufficientFunds);
+
+    Ok(())
+}
+
+/// Fails with `InsufficientFunds` unless the parlay pool's lamports, less its
+/// rent reserve, still cover its bankroll and open stakes
+pub fn assert_pool_solvent(pool: &Account<ParlayPool>) -> Result<()> {
+    let info = pool.to_account_info();
+    let rent = Rent::get()?.minimum_balance(info.data_len());
+    let available = info.lamports().saturating_sub(rent);
+    require!(
+        available as u128 >= pool.bankroll as u128 + pool.open_stake as u128,
+        Error::InsufficientFunds
+    );
+
+    Ok(())
+}
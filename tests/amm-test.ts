@@ -0,0 +1,174 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("AMM Market Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const LIQUIDITY = 10 * LAMPORTS_PER_SOL;
+
+  let trader: Keypair;
+  let eventPDA: PublicKey;
+  let positionPDA: PublicKey;
+
+  const tradeAccounts = () => ({
+    owner: trader.publicKey,
+    event: eventPDA,
+    position: positionPDA,
+    eventVault: eventPDA,
+    userTokenAccount: trader.publicKey, // Dummy for SOL betting
+    eventTokenVault: eventPDA, // Dummy for SOL betting
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  it("Setup: Create event and seed the pool", async () => {
+    trader = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(trader.publicKey, 5 * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(airdrop, "confirmed");
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    [positionPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("position"), eventPDA.toBuffer(), trader.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        100,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .seedLiquidity(new BN(LIQUIDITY))
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        authorityTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.marketType).to.deep.equal({ amm: {} });
+    expect(event.ammReserveA.toNumber()).to.equal(LIQUIDITY);
+  });
+
+  it("Buys shares at the quoted price and respects the slippage limit", async () => {
+    const amount = new BN(1 * LAMPORTS_PER_SOL);
+    const quote = await program.methods
+      .quotePayout({ winA: {} }, amount)
+      .accounts({ event: eventPDA })
+      .view();
+
+    try {
+      await program.methods
+        .buyShares({ winA: {} }, amount, quote.payout.addn(1))
+        .accounts({ ...tradeAccounts(), systemProgram: SystemProgram.programId })
+        .signers([trader])
+        .rpc();
+      throw new Error("Buy below the slippage limit should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("SlippageExceeded");
+    }
+
+    await program.methods
+      .buyShares({ winA: {} }, amount, quote.payout)
+      .accounts({ ...tradeAccounts(), systemProgram: SystemProgram.programId })
+      .signers([trader])
+      .rpc();
+
+    const position = await program.account.position.fetch(positionPDA);
+    expect(position.sharesA.toString()).to.equal(quote.payout.toString());
+    // At even odds a unit of collateral buys close to two shares
+    expect(position.sharesA.toNumber()).to.be.greaterThan(amount.toNumber());
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.ammReserveA.toNumber()).to.be.lessThan(event.ammReserveB.toNumber());
+  });
+
+  it("Sells part of the position back before the result", async () => {
+    const before = await program.account.position.fetch(positionPDA);
+    const shares = before.sharesA.divn(2);
+    const balanceBefore = await provider.connection.getBalance(trader.publicKey);
+
+    await program.methods
+      .sellShares({ winA: {} }, shares, new BN(1))
+      .accounts(tradeAccounts())
+      .signers([trader])
+      .rpc();
+
+    const received = (await provider.connection.getBalance(trader.publicKey)) - balanceBefore;
+    const after = await program.account.position.fetch(positionPDA);
+    expect(after.sharesA.toString()).to.equal(before.sharesA.sub(shares).toString());
+    expect(received).to.be.greaterThan(0);
+    console.log("   ✅ Sold", shares.toString(), "shares for", received, "lamports");
+  });
+
+  it("Redeems winning shares 1:1 after the result", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const position = await program.account.position.fetch(positionPDA);
+    const balanceBefore = await provider.connection.getBalance(trader.publicKey);
+    const rent = (await provider.connection.getAccountInfo(positionPDA))!.lamports;
+
+    await program.methods
+      .redeemShares()
+      .accounts(tradeAccounts())
+      .signers([trader])
+      .rpc();
+
+    const received = (await provider.connection.getBalance(trader.publicKey)) - balanceBefore;
+    expect(received).to.equal(position.sharesA.toNumber() + rent);
+
+    await program.methods
+      .withdrawLiquidity()
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        authorityTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.ammCollateral.toNumber()).to.equal(0);
+  });
+});
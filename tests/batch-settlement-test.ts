@@ -0,0 +1,149 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Batch Settlement Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const bets: [any, number][] = [
+    [{ winA: {} }, 1 * LAMPORTS_PER_SOL],
+    [{ winA: {} }, 2 * LAMPORTS_PER_SOL],
+    [{ winB: {} }, 1.5 * LAMPORTS_PER_SOL],
+  ];
+
+  let bettors: Keypair[];
+  let keeper: Keypair;
+  let eventPDA: PublicKey;
+  let betPDAs: PublicKey[];
+
+  it("Setup: Create SOL event, place bets and announce the winner", async () => {
+    keeper = Keypair.generate();
+    bettors = bets.map(() => Keypair.generate());
+    for (const wallet of [keeper, ...bettors]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 3 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    betPDAs = bettors.map(
+      (bettor) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("bet"), eventPDA.toBuffer(), bettor.publicKey.toBuffer()],
+          PROGRAM_ID
+        )[0]
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        200,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    for (const [i, [outcome, amount]] of bets.entries()) {
+      await program.methods
+        .createBet(outcome, new BN(amount))
+        .accounts({
+          authority: bettors[i].publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[i].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettors[i]])
+        .rpc();
+    }
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+  });
+
+  const crank = (groups: [PublicKey, PublicKey, PublicKey][]) =>
+    program.methods
+      .settleBetsBatch()
+      .accounts({
+        keeper: keeper.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        groups.flatMap(([bet, owner, destination]) => [
+          { pubkey: bet, isSigner: false, isWritable: true },
+          { pubkey: owner, isSigner: false, isWritable: true },
+          { pubkey: destination, isSigner: false, isWritable: true },
+        ])
+      )
+      .signers([keeper])
+      .rpc();
+
+  it("Rejects payouts to anyone but the bet owner", async () => {
+    try {
+      await crank([[betPDAs[0], keeper.publicKey, keeper.publicKey]]);
+      throw new Error("Payout to a foreign wallet should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidPayoutDestination");
+    }
+  });
+
+  it("Keeper settles every bet in one transaction", async () => {
+    const quotes = await Promise.all(
+      betPDAs.map((bet) =>
+        program.methods.quoteSettlement().accounts({ event: eventPDA, bet }).view()
+      )
+    );
+    const rents = await Promise.all(
+      betPDAs.map(async (bet) => (await provider.connection.getAccountInfo(bet))!.lamports)
+    );
+    const before = await Promise.all(bettors.map((b) => provider.connection.getBalance(b.publicKey)));
+
+    await crank(betPDAs.map((bet, i) => [bet, bettors[i].publicKey, bettors[i].publicKey]));
+
+    const after = await Promise.all(bettors.map((b) => provider.connection.getBalance(b.publicKey)));
+    for (const i of bets.keys()) {
+      // Winnings plus the reclaimed bet account rent
+      expect(after[i] - before[i]).to.equal(quotes[i].payout.toNumber() + rents[i]);
+      expect(await provider.connection.getAccountInfo(betPDAs[i])).to.be.null;
+    }
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.settlementFinalized).to.be.true;
+    console.log("   ✅ Settled", bets.length, "bets; paid out", event.totalPaidOut.toNumber(), "lamports");
+  });
+});
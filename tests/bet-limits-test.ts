@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Bet Limits Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const MIN_BET = 0.1 * LAMPORTS_PER_SOL;
+  const MAX_BET = 0.5 * LAMPORTS_PER_SOL;
+  const MAX_EXPOSURE = 0.8 * LAMPORTS_PER_SOL;
+
+  let user: Keypair;
+  let eventId: number;
+  let eventPDA: PublicKey;
+  let betPDA: PublicKey;
+
+  const placeBet = (outcome: any, amount: number) =>
+    program.methods
+      .createBet(outcome, new anchor.BN(amount))
+      .accounts({
+        authority: user.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: user.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+  const expectError = async (promise: Promise<any>, name: string) => {
+    try {
+      await promise;
+      throw new Error(`Expected ${name} but transaction succeeded`);
+    } catch (error) {
+      expect(error.toString()).to.include(name);
+      console.log("   ✅ Rejected with", name);
+    }
+  };
+
+  it("Setup: Create wallet and SOL event with limits", async () => {
+    user = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(user.publicKey, 3 * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(airdrop, "confirmed");
+
+    eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    [betPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), user.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new anchor.BN(eventId),
+        "Team A",
+        "Team B",
+        300,
+        { stake: {} },
+        null,
+        new anchor.BN(MIN_BET),
+        new anchor.BN(MAX_BET),
+        new anchor.BN(MAX_EXPOSURE)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.minBet.toNumber()).to.equal(MIN_BET);
+    expect(event.maxBet.toNumber()).to.equal(MAX_BET);
+    expect(event.maxWalletExposure.toNumber()).to.equal(MAX_EXPOSURE);
+  });
+
+  it("Rejects bets outside the per-bet limits", async () => {
+    await expectError(placeBet({ winA: {} }, MIN_BET - 1), "BetBelowMinimum");
+    await expectError(placeBet({ winA: {} }, MAX_BET + 1), "BetAboveMaximum");
+  });
+
+  it("Tops up a position until the wallet exposure cap", async () => {
+    await placeBet({ winA: {} }, MAX_BET);
+    await placeBet({ winA: {} }, 0.3 * LAMPORTS_PER_SOL);
+
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.amount.toNumber()).to.equal(MAX_EXPOSURE);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.winACount).to.equal(1);
+
+    await expectError(placeBet({ winA: {} }, MIN_BET), "WalletExposureExceeded");
+    await expectError(placeBet({ winB: {} }, MIN_BET), "OutcomeMismatch");
+  });
+
+  it("Authority updates limits while betting is open", async () => {
+    await program.methods
+      .updateBetLimits(new anchor.BN(MIN_BET), new anchor.BN(MAX_BET), new anchor.BN(0))
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+      })
+      .rpc();
+
+    await placeBet({ winA: {} }, MIN_BET);
+    const bet = await program.account.bet.fetch(betPDA);
+    expect(bet.amount.toNumber()).to.equal(MAX_EXPOSURE + MIN_BET);
+  });
+
+  it("Rejects limit updates after betting closes", async () => {
+    await program.methods
+      .closeBetting()
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+      })
+      .rpc();
+
+    await expectError(
+      program.methods
+        .updateBetLimits(new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          authority: provider.wallet.publicKey,
+          event: eventPDA,
+        })
+        .rpc(),
+      "BettingClosed"
+    );
+  });
+});
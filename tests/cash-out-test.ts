@@ -0,0 +1,172 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Cash Out Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const bets: [any, number][] = [
+    [{ winA: {} }, 1 * LAMPORTS_PER_SOL],
+    [{ winA: {} }, 1 * LAMPORTS_PER_SOL],
+    [{ winB: {} }, 3 * LAMPORTS_PER_SOL],
+  ];
+  const totalStaked = bets.reduce((sum, [, amount]) => sum + amount, 0);
+  const CASH_OUT_FEE_BPS = 200; // 2%
+
+  let bettors: Keypair[];
+  let eventPDA: PublicKey;
+
+  const betPDA = (bettor: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), bettor.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  const cashOutAccounts = (bettor: Keypair) => ({
+    authority: bettor.publicKey,
+    bet: betPDA(bettor),
+    event: eventPDA,
+    eventVault: eventPDA,
+    userTokenAccount: bettor.publicKey, // Dummy for SOL betting
+    eventTokenVault: eventPDA, // Dummy for SOL betting
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  it("Setup: Create SOL event and place bets", async () => {
+    bettors = bets.map(() => Keypair.generate());
+    for (const wallet of bettors) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 4 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        500,
+        { netWinnings: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .setCashOutFee(CASH_OUT_FEE_BPS)
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    for (const [i, [outcome, amount]] of bets.entries()) {
+      await program.methods
+        .createBet(outcome, new BN(amount))
+        .accounts({
+          authority: bettors[i].publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[i].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettors[i]])
+        .rpc();
+    }
+
+    // The cash-out fee is fixed once bets are in
+    try {
+      await program.methods
+        .setCashOutFee(0)
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+        .rpc();
+      throw new Error("Changing the cash-out fee after bets should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMarketType");
+    }
+  });
+
+  it("Rejects a cash-out below the minimum amount", async () => {
+    try {
+      await program.methods
+        .cashOut(new BN(bets[0][1]))
+        .accounts(cashOutAccounts(bettors[0]))
+        .signers([bettors[0]])
+        .rpc();
+      throw new Error("Cash-out should have failed the slippage check");
+    } catch (error) {
+      expect(error.toString()).to.include("SlippageExceeded");
+    }
+  });
+
+  it("Cashes out a bet and removes its stake from the pool", async () => {
+    await program.methods
+      .cashOut(new BN(0))
+      .accounts(cashOutAccounts(bettors[0]))
+      .signers([bettors[0]])
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    const value = event.totalPaidOut.toNumber();
+    const fee = event.accruedFees.toNumber();
+
+    // Worth 2.425 SOL if WinA wins; the other bettors' stakes put WinA at 1 in 4
+    const ifWon = 2_425_000_000;
+    const fairValue = ifWon / 4;
+    const expected = fairValue - Math.ceil((fairValue * CASH_OUT_FEE_BPS) / 10000);
+
+    console.log("\n💸 Cash-out value:", value, "fee:", fee);
+    expect(value).to.equal(expected);
+    expect(value + fee).to.equal(bets[0][1]);
+    expect(event.winAAmount.toNumber()).to.equal(bets[1][1]);
+    expect(event.winACount).to.equal(1);
+    expect(event.totalCashedOut.toNumber()).to.equal(bets[0][1]);
+    expect(await provider.connection.getAccountInfo(betPDA(bettors[0]))).to.be.null;
+  });
+
+  it("Settles the remaining bets against the reduced pool", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    await program.methods
+      .settleBet()
+      .accounts({
+        ...cashOutAccounts(bettors[1]),
+        tokenMint: eventPDA, // Dummy for SOL betting
+      })
+      .signers([bettors[1]])
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.settlementFinalized).to.be.true;
+    expect(event.totalPaidOut.toNumber() + event.totalFees.toNumber()).to.equal(totalStaked);
+  });
+});
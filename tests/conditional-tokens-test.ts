@@ -0,0 +1,179 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccount,
+  getAccount,
+} from "@solana/spl-token";
+
+describe("Conditional Tokens Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const SPLIT_AMOUNT = 2 * LAMPORTS_PER_SOL;
+  const MERGE_AMOUNT = 1 * LAMPORTS_PER_SOL;
+
+  let user: Keypair;
+  let eventPDA: PublicKey;
+  let mintA: PublicKey;
+  let mintB: PublicKey;
+  let userOutcomeA: PublicKey;
+  let userOutcomeB: PublicKey;
+
+  const vaultAccounts = () => ({
+    eventVault: eventPDA,
+    userTokenAccount: user.publicKey, // Dummy for SOL betting
+    eventTokenVault: eventPDA, // Dummy for SOL betting
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  it("Setup: Create event and enable outcome tokens", async () => {
+    user = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(user.publicKey, 5 * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(airdrop, "confirmed");
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    [mintA] = PublicKey.findProgramAddressSync([Buffer.from("mint_a"), eventPDA.toBuffer()], PROGRAM_ID);
+    [mintB] = PublicKey.findProgramAddressSync([Buffer.from("mint_b"), eventPDA.toBuffer()], PROGRAM_ID);
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        100,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .enableConditionalTokens(9)
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+        tokenMint: eventPDA, // Dummy for SOL betting
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.marketType).to.deep.equal({ conditionalTokens: {} });
+    expect(event.outcomeMintA.toBase58()).to.equal(mintA.toBase58());
+    expect(event.outcomeMintB.toBase58()).to.equal(mintB.toBase58());
+
+    userOutcomeA = await createAssociatedTokenAccount(provider.connection, user, mintA, user.publicKey);
+    userOutcomeB = await createAssociatedTokenAccount(provider.connection, user, mintB, user.publicKey);
+  });
+
+  it("Splits collateral into a complete set of outcome tokens", async () => {
+    await program.methods
+      .splitPosition(new BN(SPLIT_AMOUNT))
+      .accounts({
+        user: user.publicKey,
+        event: eventPDA,
+        outcomeMintA: mintA,
+        outcomeMintB: mintB,
+        userOutcomeA,
+        userOutcomeB,
+        ...vaultAccounts(),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    expect(Number((await getAccount(provider.connection, userOutcomeA)).amount)).to.equal(SPLIT_AMOUNT);
+    expect(Number((await getAccount(provider.connection, userOutcomeB)).amount)).to.equal(SPLIT_AMOUNT);
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.conditionalCollateral.toNumber()).to.equal(SPLIT_AMOUNT);
+  });
+
+  it("Merges a complete set back into collateral", async () => {
+    const before = await provider.connection.getBalance(user.publicKey);
+
+    await program.methods
+      .mergePositions(new BN(MERGE_AMOUNT))
+      .accounts({
+        user: user.publicKey,
+        event: eventPDA,
+        outcomeMintA: mintA,
+        outcomeMintB: mintB,
+        userOutcomeA,
+        userOutcomeB,
+        ...vaultAccounts(),
+      })
+      .signers([user])
+      .rpc();
+
+    const after = await provider.connection.getBalance(user.publicKey);
+    expect(after).to.be.greaterThan(before);
+    expect(Number((await getAccount(provider.connection, userOutcomeA)).amount)).to.equal(SPLIT_AMOUNT - MERGE_AMOUNT);
+    expect(Number((await getAccount(provider.connection, userOutcomeB)).amount)).to.equal(SPLIT_AMOUNT - MERGE_AMOUNT);
+  });
+
+  it("Redeems only the winning token after resolution", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const remaining = SPLIT_AMOUNT - MERGE_AMOUNT;
+
+    try {
+      await program.methods
+        .redeemOutcomeTokens(new BN(remaining))
+        .accounts({
+          user: user.publicKey,
+          event: eventPDA,
+          winningMint: mintB,
+          userOutcomeTokens: userOutcomeB,
+          ...vaultAccounts(),
+        })
+        .signers([user])
+        .rpc();
+      throw new Error("Redeeming the losing token should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMint");
+    }
+
+    await program.methods
+      .redeemOutcomeTokens(new BN(remaining))
+      .accounts({
+        user: user.publicKey,
+        event: eventPDA,
+        winningMint: mintA,
+        userOutcomeTokens: userOutcomeA,
+        ...vaultAccounts(),
+      })
+      .signers([user])
+      .rpc();
+
+    expect(Number((await getAccount(provider.connection, userOutcomeA)).amount)).to.equal(0);
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.conditionalCollateral.toNumber()).to.equal(0);
+    expect(event.totalPaidOut.toNumber()).to.equal(remaining);
+  });
+});
@@ -0,0 +1,127 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import { PublicKey } from "@solana/web3.js";
+
+describe("Derived Markets Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  // Final score 2-1
+  const markets: [any, any][] = [
+    [{ moneyline: {} }, { winA: {} }],
+    [{ handicap: { lineHalves: -3 } }, { winB: {} }], // A -1.5 fails to cover
+    [{ total: { lineHalves: 5 } }, { winA: {} }], // over 2.5
+    [{ total: { lineHalves: 6 } }, { invalid: {} }], // 3 exactly pushes
+    [{ exactScore: { scoreA: 2, scoreB: 1 } }, { winA: {} }],
+    [{ scalar: { min: new BN(0), max: new BN(12) } }, { undrawn: {} }], // resolves from a value
+  ];
+
+  let fixturePDA: PublicKey;
+  let eventPDAs: PublicKey[];
+
+  it("Setup: Create one market per line on a fixture", async () => {
+    const fixtureId = Math.floor(Math.random() * 1000000);
+    const fixtureIdBuffer = Buffer.alloc(8);
+    fixtureIdBuffer.writeUInt32LE(fixtureId, 0);
+    [fixturePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fixture"), fixtureIdBuffer],
+      PROGRAM_ID
+    );
+    eventPDAs = [];
+
+    await program.methods
+      .createFixture(new BN(fixtureId), "Team A", "Team B", "Premier League", new BN(Math.floor(Date.now() / 1000) + 3600))
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+
+    for (const [kind] of markets) {
+      const eventId = Math.floor(Math.random() * 1000000);
+      const eventIdBuffer = Buffer.alloc(8);
+      eventIdBuffer.writeUInt32LE(eventId, 0);
+      const [eventPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("event"), eventIdBuffer],
+        PROGRAM_ID
+      );
+      eventPDAs.push(eventPDA);
+
+      await program.methods
+        .createEvent(
+          new BN(eventId),
+          "Team A",
+          "Team B",
+          100,
+          { stake: {} },
+          null,
+          new BN(0),
+          new BN(0),
+          new BN(0)
+        )
+        .accounts({
+          authority: provider.wallet.publicKey,
+          platformFeeAccount: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .setMarketKind(kind)
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+        .rpc();
+
+      await program.methods
+        .addFixtureMarket()
+        .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA, event: eventPDA })
+        .rpc();
+    }
+
+    const fixture = await program.account.fixture.fetch(fixturePDA);
+    expect(fixture.marketCount).to.equal(markets.length);
+    expect(fixture.status).to.deep.equal({ scheduled: {} });
+
+    const event = await program.account.event.fetch(eventPDAs[1]);
+    expect(event.fixture.toString()).to.equal(fixturePDA.toString());
+    expect(event.marketKind).to.deep.equal({ handicap: { lineHalves: -3 } });
+  });
+
+  it("Rejects a score report that leaves out a market", async () => {
+    try {
+      await program.methods
+        .reportScore(2, 1)
+        .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA })
+        .remainingAccounts(eventPDAs.slice(1).map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+        .rpc();
+      throw new Error("Missing market should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidFixture");
+    }
+  });
+
+  it("Resolves every market from a single reported score", async () => {
+    await program.methods
+      .reportScore(2, 1)
+      .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA })
+      .remainingAccounts(eventPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+    const fixture = await program.account.fixture.fetch(fixturePDA);
+    expect(fixture.status).to.deep.equal({ completed: {} });
+    expect(fixture.scoreA).to.equal(2);
+    expect(fixture.scoreB).to.equal(1);
+
+    for (const [i, [, expected]] of markets.entries()) {
+      const event = await program.account.event.fetch(eventPDAs[i]);
+      expect(event.outcome).to.deep.equal(expected);
+      if ("undrawn" in expected) {
+        expect(event.scoreReported).to.be.false;
+        continue;
+      }
+      expect(event.scoreReported).to.be.true;
+      expect(event.scoreA).to.equal(2);
+      expect(event.scoreB).to.equal(1);
+    }
+  });
+});
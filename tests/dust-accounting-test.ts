@@ -0,0 +1,250 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Dust Accounting Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  // Stakes chosen so that every pro-rata share leaves a remainder
+  const bets: [any, number][] = [
+    [{ winA: {} }, 100_000_001],
+    [{ winA: {} }, 200_000_003],
+    [{ winA: {} }, 300_000_007],
+    [{ winB: {} }, 700_000_009],
+  ];
+
+  let bettors: Keypair[];
+  let treasury: Keypair;
+  let eventPDA: PublicKey;
+
+  it("Setup: Create SOL event and place bets", async () => {
+    treasury = Keypair.generate();
+    bettors = bets.map(() => Keypair.generate());
+    for (const wallet of [treasury, ...bettors]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 2 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        333,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: treasury.publicKey,
+      })
+      .rpc();
+
+    for (const [i, [outcome, amount]] of bets.entries()) {
+      await program.methods
+        .createBet(outcome, new BN(amount))
+        .accounts({
+          authority: bettors[i].publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[i].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettors[i]])
+        .rpc();
+    }
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+  });
+
+  it("Refuses to close the event while winners are still owed", async () => {
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.outstandingStake.toNumber()).to.equal(600_000_011);
+    expect(event.outstandingLosingShare.toNumber()).to.equal(700_000_009);
+
+    try {
+      await program.methods
+        .closeEvent()
+        .accounts({
+          authority: provider.wallet.publicKey,
+          event: eventPDA,
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      throw new Error("Close should have failed with outstanding liabilities");
+    } catch (error) {
+      expect(error.toString()).to.include("InsufficientFunds");
+    }
+  });
+
+  it("Sweeps dust to the treasury after the last winning claim", async () => {
+    for (const [i, [outcome]] of bets.entries()) {
+      if (!outcome.winA) continue;
+      const [betPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bet"), eventPDA.toBuffer(), bettors[i].publicKey.toBuffer()],
+        PROGRAM_ID
+      );
+      await program.methods
+        .settleBet()
+        .accounts({
+          authority: bettors[i].publicKey,
+          bet: betPDA,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[i].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([bettors[i]])
+        .rpc();
+    }
+
+    const event = await program.account.event.fetch(eventPDA);
+    const totalPool = bets.reduce((sum, [, amount]) => sum + amount, 0);
+
+    console.log("\n📊 Settlement totals:");
+    console.log("   Total Pool:        ", totalPool);
+    console.log("   Total Paid Out:    ", event.totalPaidOut.toNumber());
+    console.log("   Total Fees + Dust: ", event.totalFees.toNumber());
+
+    expect(event.settlementFinalized).to.be.true;
+    expect(event.totalPaidOut.toNumber() + event.totalFees.toNumber()).to.equal(totalPool);
+    expect(event.accruedFees.toNumber()).to.equal(event.totalFees.toNumber());
+    expect(event.outstandingStake.toNumber()).to.equal(0);
+    expect(event.outstandingLosingShare.toNumber()).to.equal(0);
+  });
+
+  it("Leaves the vault at exactly its rent reserve after fees are withdrawn", async () => {
+    await program.methods
+      .withdrawFees()
+      .accounts({
+        treasury: treasury.publicKey,
+        event: eventPDA,
+        feeDestination: treasury.publicKey,
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([treasury])
+      .rpc();
+
+    const info = await provider.connection.getAccountInfo(eventPDA);
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(info!.data.length);
+    expect(info!.lamports).to.equal(rent);
+    console.log("   ✅ Vault holds only its rent reserve:", rent, "lamports");
+
+    await program.methods
+      .closeEvent()
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it("Refunds every stake when nobody backed the winner", async () => {
+    const stake = 100_000_003;
+    const bettor = bettors[3];
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [emptyWinnerPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        333,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: treasury.publicKey,
+      })
+      .rpc();
+
+    const accounts = {
+      authority: bettor.publicKey,
+      event: emptyWinnerPDA,
+      eventVault: emptyWinnerPDA,
+      userTokenAccount: bettor.publicKey, // Dummy for SOL betting
+      eventTokenVault: emptyWinnerPDA, // Dummy for SOL betting
+      tokenMint: emptyWinnerPDA, // Dummy for SOL betting
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+    await program.methods
+      .createBet({ winB: {} }, new BN(stake))
+      .accounts({
+        ...accounts,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bettor])
+      .rpc();
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: emptyWinnerPDA })
+      .rpc();
+
+    let event = await program.account.event.fetch(emptyWinnerPDA);
+    expect(event.settlementFinalized).to.be.false;
+    expect(event.outstandingStake.toNumber()).to.equal(stake);
+
+    const [betPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), emptyWinnerPDA.toBuffer(), bettor.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+    await program.methods
+      .settleBet()
+      .accounts({ ...accounts, bet: betPDA })
+      .signers([bettor])
+      .rpc();
+
+    event = await program.account.event.fetch(emptyWinnerPDA);
+    expect(event.settlementFinalized).to.be.true;
+    expect(event.totalPaidOut.toNumber()).to.equal(stake);
+    expect(event.totalFees.toNumber()).to.equal(0);
+  });
+});
@@ -0,0 +1,185 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Early Bird Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const EARLY_BIRD_CAP = 1 * LAMPORTS_PER_SOL;
+  const BONUS_BPS = 1000; // 10% of the eligible stake
+  const PROMO_BUDGET = 0.5 * LAMPORTS_PER_SOL;
+  // The first bet on each side fills that side's cap
+  const bets: [number, any][] = [
+    [1.5 * LAMPORTS_PER_SOL, { winA: {} }],
+    [1 * LAMPORTS_PER_SOL, { winA: {} }],
+    [1 * LAMPORTS_PER_SOL, { winB: {} }],
+  ];
+
+  let bettors: Keypair[];
+  let eventPDA: PublicKey;
+
+  const betPDA = (bettor: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), bettor.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  const vaultAccounts = () => ({
+    authority: provider.wallet.publicKey,
+    event: eventPDA,
+    eventVault: eventPDA,
+    authorityTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
+    eventTokenVault: eventPDA, // Dummy for SOL betting
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  it("Setup: Create an event with a funded early-bird promo", async () => {
+    bettors = bets.map(() => Keypair.generate());
+    for (const wallet of bettors) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 5 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        0,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .setEarlyBird(new BN(EARLY_BIRD_CAP), new BN(0), BONUS_BPS)
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    // Early-bird bonuses assume a single winning side, so no scalar range
+    try {
+      await program.methods
+        .setMarketKind({ scalar: { min: new BN(0), max: new BN(100) } })
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+        .rpc();
+      throw new Error("Making an early-bird event scalar should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMarketType");
+    }
+
+    await program.methods
+      .fundPromo(new BN(PROMO_BUDGET))
+      .accounts(vaultAccounts())
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.promoBudget.toNumber()).to.equal(PROMO_BUDGET);
+  });
+
+  it("Records early-bird eligibility on the first stakes per side", async () => {
+    for (const [i, [amount, outcome]] of bets.entries()) {
+      await program.methods
+        .createBet(outcome, new BN(amount))
+        .accounts({
+          authority: bettors[i].publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[i].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettors[i]])
+        .rpc();
+    }
+
+    const expected = [EARLY_BIRD_CAP, 0, EARLY_BIRD_CAP];
+    for (const [i, eligible] of expected.entries()) {
+      const bet = await program.account.bet.fetch(betPDA(bettors[i]));
+      expect(bet.earlyBirdAmount.toNumber()).to.equal(eligible);
+      expect(bet.earlyBirdBonus.toNumber()).to.equal((eligible * BONUS_BPS) / 10000);
+    }
+  });
+
+  it("Keeps reserved bonuses out of promo withdrawals", async () => {
+    const unreserved = PROMO_BUDGET - 2 * (EARLY_BIRD_CAP * BONUS_BPS) / 10000;
+    try {
+      await program.methods
+        .withdrawPromo(new BN(unreserved + 1))
+        .accounts(vaultAccounts())
+        .rpc();
+      throw new Error("Withdrawing reserved promo funds should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("PromoBudgetExceeded");
+    }
+  });
+
+  it("Pays winning early birds their bonus and frees the losers' reservations", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    // Only the winning early bird's bonus is still owed
+    const bonus = (EARLY_BIRD_CAP * BONUS_BPS) / 10000;
+    await program.methods
+      .withdrawPromo(new BN(PROMO_BUDGET - bonus))
+      .accounts(vaultAccounts())
+      .rpc();
+
+    const bettor = bettors[0];
+    const balanceBefore = await provider.connection.getBalance(bettor.publicKey);
+    await program.methods
+      .settleBet()
+      .accounts({
+        authority: bettor.publicKey,
+        bet: betPDA(bettor),
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: bettor.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([bettor])
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(bettor.publicKey);
+
+    const winningPool = bets[0][0] + bets[1][0];
+    const payout = bets[0][0] + Math.floor((bets[0][0] * bets[2][0]) / winningPool);
+    expect(balanceAfter - balanceBefore).to.be.greaterThan(payout + bonus - 10000);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.promoBudget.toNumber()).to.equal(0);
+    expect(event.totalPaidOut.toNumber()).to.equal(payout);
+  });
+});
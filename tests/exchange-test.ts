@@ -0,0 +1,321 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Exchange Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const FEE_BPS = 500; // 5% commission
+  const ODDS_BPS = 25000; // 2.5
+  const OFFER_STAKE = 1 * LAMPORTS_PER_SOL;
+  const MATCHED_STAKE = 0.4 * LAMPORTS_PER_SOL;
+  const LIABILITY = (MATCHED_STAKE * (ODDS_BPS - 10000)) / 10000;
+
+  let backer: Keypair;
+  let layer: Keypair;
+  let eventPDA: PublicKey;
+  let offerPDA: PublicKey;
+  let matchedBetPDA: PublicKey;
+
+  const vaultAccounts = (user: Keypair) => ({
+    eventVault: eventPDA,
+    userTokenAccount: user.publicKey, // Dummy for SOL betting
+    eventTokenVault: eventPDA, // Dummy for SOL betting
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  it("Setup: Create event and post a back offer", async () => {
+    backer = Keypair.generate();
+    layer = Keypair.generate();
+    for (const wallet of [backer, layer]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 3 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    [offerPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("offer"), eventPDA.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+      PROGRAM_ID
+    );
+    [matchedBetPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("matched"), offerPDA.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 4)],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        FEE_BPS,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .postOffer({ winA: {} }, { back: {} }, ODDS_BPS, new BN(OFFER_STAKE))
+      .accounts({
+        maker: backer.publicKey,
+        event: eventPDA,
+        offer: offerPDA,
+        ...vaultAccounts(backer),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([backer])
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.marketType).to.deep.equal({ exchange: {} });
+    expect(event.exchangeEscrow.toNumber()).to.equal(OFFER_STAKE);
+  });
+
+  it("Rejects matching your own offer", async () => {
+    try {
+      await program.methods
+        .matchOffer(new BN(MATCHED_STAKE))
+        .accounts({
+          taker: backer.publicKey,
+          event: eventPDA,
+          offer: offerPDA,
+          matchedBet: matchedBetPDA,
+          ...vaultAccounts(backer),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([backer])
+        .rpc();
+      throw new Error("Self-match should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("SelfMatch");
+    }
+  });
+
+  it("Partially fills the offer with a lay", async () => {
+    await program.methods
+      .matchOffer(new BN(MATCHED_STAKE))
+      .accounts({
+        taker: layer.publicKey,
+        event: eventPDA,
+        offer: offerPDA,
+        matchedBet: matchedBetPDA,
+        ...vaultAccounts(layer),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([layer])
+      .rpc();
+
+    const offer = await program.account.offer.fetch(offerPDA);
+    expect(offer.stakeRemaining.toNumber()).to.equal(OFFER_STAKE - MATCHED_STAKE);
+    expect(offer.escrow.toNumber()).to.equal(OFFER_STAKE - MATCHED_STAKE);
+
+    const matched = await program.account.matchedBet.fetch(matchedBetPDA);
+    expect(matched.backer.toBase58()).to.equal(backer.publicKey.toBase58());
+    expect(matched.layer.toBase58()).to.equal(layer.publicKey.toBase58());
+    expect(matched.backStake.toNumber()).to.equal(MATCHED_STAKE);
+    expect(matched.layLiability.toNumber()).to.equal(LIABILITY);
+  });
+
+  it("Cancels the unmatched remainder", async () => {
+    await program.methods
+      .cancelOffer()
+      .accounts({
+        maker: backer.publicKey,
+        event: eventPDA,
+        offer: offerPDA,
+        ...vaultAccounts(backer),
+      })
+      .signers([backer])
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.exchangeEscrow.toNumber()).to.equal(MATCHED_STAKE + LIABILITY);
+    expect(await provider.connection.getAccountInfo(offerPDA)).to.be.null;
+  });
+
+  it("Pays the winning backer less commission on net winnings", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    // The loser cannot claim the pot
+    try {
+      await program.methods
+        .settleMatchedBet()
+        .accounts({
+          winner: layer.publicKey,
+          event: eventPDA,
+          matchedBet: matchedBetPDA,
+          taker: layer.publicKey,
+          ...vaultAccounts(layer),
+        })
+        .signers([layer])
+        .rpc();
+      throw new Error("Loser settlement should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("Unauthorized");
+    }
+
+    await program.methods
+      .settleMatchedBet()
+      .accounts({
+        winner: backer.publicKey,
+        event: eventPDA,
+        matchedBet: matchedBetPDA,
+        taker: layer.publicKey,
+        ...vaultAccounts(backer),
+      })
+      .signers([backer])
+      .rpc();
+
+    const commission = Math.floor((LIABILITY * FEE_BPS) / 10000);
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.exchangeEscrow.toNumber()).to.equal(0);
+    expect(event.accruedFees.toNumber()).to.equal(commission);
+    expect(event.totalPaidOut.toNumber()).to.equal(MATCHED_STAKE + LIABILITY - commission);
+    expect(await provider.connection.getAccountInfo(matchedBetPDA)).to.be.null;
+  });
+
+  it("Refunds both sides of a matched bet when the market is voided", async () => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [voidEventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    const [voidOfferPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("offer"), voidEventPDA.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+      PROGRAM_ID
+    );
+    const [voidMatchedBetPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("matched"), voidOfferPDA.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 4)],
+      PROGRAM_ID
+    );
+    const voidVaultAccounts = (user: Keypair) => ({
+      eventVault: voidEventPDA,
+      userTokenAccount: user.publicKey, // Dummy for SOL betting
+      eventTokenVault: voidEventPDA, // Dummy for SOL betting
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        FEE_BPS,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .postOffer({ winA: {} }, { back: {} }, ODDS_BPS, new BN(MATCHED_STAKE))
+      .accounts({
+        maker: backer.publicKey,
+        event: voidEventPDA,
+        offer: voidOfferPDA,
+        ...voidVaultAccounts(backer),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([backer])
+      .rpc();
+
+    await program.methods
+      .matchOffer(new BN(MATCHED_STAKE))
+      .accounts({
+        taker: layer.publicKey,
+        event: voidEventPDA,
+        offer: voidOfferPDA,
+        matchedBet: voidMatchedBetPDA,
+        ...voidVaultAccounts(layer),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([layer])
+      .rpc();
+
+    await program.methods
+      .voidEvent()
+      .accounts({ authority: provider.wallet.publicKey, event: voidEventPDA })
+      .rpc();
+
+    // A void has no winner to pay
+    try {
+      await program.methods
+        .settleMatchedBet()
+        .accounts({
+          winner: layer.publicKey,
+          event: voidEventPDA,
+          matchedBet: voidMatchedBetPDA,
+          taker: layer.publicKey,
+          ...voidVaultAccounts(layer),
+        })
+        .signers([layer])
+        .rpc();
+      throw new Error("Settling a voided matched bet should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidOutcome");
+    }
+
+    const backerBefore = await provider.connection.getBalance(backer.publicKey);
+    const layerBefore = await provider.connection.getBalance(layer.publicKey);
+
+    await program.methods
+      .refundMatchedBet()
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: voidEventPDA,
+        matchedBet: voidMatchedBetPDA,
+        backer: backer.publicKey,
+        layer: layer.publicKey,
+        taker: layer.publicKey,
+        eventVault: voidEventPDA,
+        backerTokenAccount: backer.publicKey, // Dummy for SOL betting
+        layerTokenAccount: layer.publicKey, // Dummy for SOL betting
+        eventTokenVault: voidEventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(voidEventPDA);
+    expect(event.exchangeEscrow.toNumber()).to.equal(0);
+    expect(event.accruedFees.toNumber()).to.equal(0);
+    expect(await provider.connection.getBalance(backer.publicKey) - backerBefore).to.equal(MATCHED_STAKE);
+    // The layer also recovers the matched bet's rent as its taker
+    expect(await provider.connection.getBalance(layer.publicKey) - layerBefore).to.be.greaterThan(LIABILITY);
+    expect(await provider.connection.getAccountInfo(voidMatchedBetPDA)).to.be.null;
+  });
+});
@@ -0,0 +1,166 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Fee Modes Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  // Odd amounts so every mode has to round
+  const WINNER_STAKE = new BN(300_000_001);
+  const LOSER_STAKE = new BN(700_000_003);
+  const FEE_BPS = 250;
+  const BPS = new BN(10000);
+
+  let feeAccount: Keypair;
+
+  // Mirrors the on-chain formulas; every division rounds down
+  const expectedSettlement = (mode: string, stake: BN, winningPool: BN, losingPool: BN) => {
+    const bps = new BN(FEE_BPS);
+    if (mode === "stake") {
+      const fee = stake.mul(bps).div(BPS);
+      const net = stake.sub(fee);
+      return { payout: net.add(net.mul(losingPool).div(winningPool)), fee };
+    }
+    if (mode === "netWinnings") {
+      const share = stake.mul(losingPool).div(winningPool);
+      const fee = share.mul(bps).div(BPS);
+      return { payout: stake.add(share).sub(fee), fee };
+    }
+    const total = winningPool.add(losingPool);
+    const take = total.mul(bps).div(BPS);
+    const gross = stake.mul(total).div(winningPool);
+    const payout = stake.mul(total.sub(take)).div(winningPool);
+    return { payout, fee: gross.sub(payout) };
+  };
+
+  const runMode = async (mode: string) => {
+    const winner = Keypair.generate();
+    const loser = Keypair.generate();
+    for (const user of [winner, loser]) {
+      const airdrop = await provider.connection.requestAirdrop(user.publicKey, 2 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        FEE_BPS,
+        { [mode]: {} } as any,
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: feeAccount.publicKey,
+      })
+      .rpc();
+
+    for (const [user, outcome, amount] of [
+      [winner, { winA: {} }, WINNER_STAKE],
+      [loser, { winB: {} }, LOSER_STAKE],
+    ] as [Keypair, any, BN][]) {
+      await program.methods
+        .createBet(outcome, amount)
+        .accounts({
+          authority: user.publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: user.publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+      })
+      .rpc();
+
+    const [betPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), winner.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    const winnerBefore = await provider.connection.getBalance(winner.publicKey);
+
+    // Provider pays the transaction fee so balance changes are exact
+    await program.methods
+      .settleBet()
+      .accounts({
+        authority: winner.publicKey,
+        bet: betPDA,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: winner.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([winner])
+      .rpc();
+
+    const payout = (await provider.connection.getBalance(winner.publicKey)) - winnerBefore;
+    const fee = (await program.account.event.fetch(eventPDA)).accruedFees.toNumber();
+    const expected = expectedSettlement(mode, WINNER_STAKE, WINNER_STAKE, LOSER_STAKE);
+
+    console.log(`\n📊 ${mode}: payout ${payout} lamports, fee ${fee} lamports`);
+    expect(payout).to.equal(expected.payout.toNumber());
+    expect(fee).to.equal(expected.fee.toNumber());
+    expect(payout + fee).to.be.at.most(WINNER_STAKE.add(LOSER_STAKE).toNumber());
+    return { payout, fee };
+  };
+
+  before(async () => {
+    feeAccount = Keypair.generate();
+  });
+
+  it("Charges the fee on the winning stake", async () => {
+    const { fee } = await runMode("stake");
+    expect(fee).to.equal(WINNER_STAKE.muln(FEE_BPS).div(BPS).toNumber());
+  });
+
+  it("Charges the fee on net winnings only", async () => {
+    const { payout } = await runMode("netWinnings");
+    // A winner never gets back less than the stake
+    expect(payout).to.be.greaterThan(WINNER_STAKE.toNumber());
+  });
+
+  it("Takes the house cut from the total pool", async () => {
+    const { payout, fee } = await runMode("poolTake");
+    const total = WINNER_STAKE.add(LOSER_STAKE);
+    // The only winner receives the whole pool minus the take
+    expect(payout + fee).to.equal(total.toNumber());
+  });
+});
@@ -0,0 +1,212 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Fee Split Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const FEE_BPS = 1000; // 10%
+  const WINNER_STAKE = 1 * LAMPORTS_PER_SOL + 7;
+  const LOSER_STAKE = 1 * LAMPORTS_PER_SOL;
+
+  let winner: Keypair;
+  let loser: Keypair;
+  let platform: Keypair;
+  let creator: Keypair;
+  let affiliate: Keypair;
+  let eventPDA: PublicKey;
+
+  it("Setup: Create SOL event with a 70/20/10 fee split", async () => {
+    [winner, loser, platform, creator, affiliate] = Array.from({ length: 5 }, () => Keypair.generate());
+    for (const wallet of [winner, loser, platform, creator, affiliate]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 2 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        FEE_BPS,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: platform.publicKey,
+      })
+      .rpc();
+
+    // The creator cannot redirect fees without the treasury's signature
+    try {
+      await program.methods
+        .setFeeSplit([{ recipient: creator.publicKey, shareBps: 10000 }])
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA, treasury: creator.publicKey })
+        .signers([creator])
+        .rpc();
+      throw new Error("Split without the treasury should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("Unauthorized");
+    }
+
+    // Shares that don't sum to 100% are rejected
+    try {
+      await program.methods
+        .setFeeSplit([
+          { recipient: platform.publicKey, shareBps: 7000 },
+          { recipient: creator.publicKey, shareBps: 2000 },
+        ])
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA, treasury: platform.publicKey })
+        .signers([platform])
+        .rpc();
+      throw new Error("Invalid split should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidFeeSplit");
+    }
+
+    await program.methods
+      .setFeeSplit([
+        { recipient: platform.publicKey, shareBps: 7000 },
+        { recipient: creator.publicKey, shareBps: 2000 },
+        { recipient: affiliate.publicKey, shareBps: 1000 },
+      ])
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA, treasury: platform.publicKey })
+      .signers([platform])
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.feeSplit.length).to.equal(3);
+  });
+
+  it("Settles a winning bet and splits the fee on withdrawal", async () => {
+    for (const [user, outcome, amount] of [
+      [winner, { winA: {} }, WINNER_STAKE],
+      [loser, { winB: {} }, LOSER_STAKE],
+    ] as [Keypair, any, number][]) {
+      await program.methods
+        .createBet(outcome, new BN(amount))
+        .accounts({
+          authority: user.publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: user.publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const [betPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), winner.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .settleBet()
+      .accounts({
+        authority: winner.publicKey,
+        bet: betPDA,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: winner.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([winner])
+      .rpc();
+
+    const fee = Math.floor((WINNER_STAKE * FEE_BPS) / 10000);
+    let event = await program.account.event.fetch(eventPDA);
+    expect(event.accruedFees.toNumber()).to.equal(fee);
+
+    const recipients = [platform, creator, affiliate];
+    const before = await Promise.all(recipients.map((r) => provider.connection.getBalance(r.publicKey)));
+
+    // Listing the primary event again as an extra event would pay its fees twice
+    try {
+      await program.methods
+        .withdrawFees()
+        .accounts({
+          treasury: platform.publicKey,
+          event: eventPDA,
+          feeDestination: platform.publicKey,
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          ...recipients.map((r) => ({ pubkey: r.publicKey, isSigner: false, isWritable: true })),
+          { pubkey: eventPDA, isSigner: false, isWritable: true },
+        ])
+        .signers([platform])
+        .rpc();
+      throw new Error("Sweeping the primary event twice should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidEvent");
+    }
+
+    // The treasury signs; the provider pays the transaction fee so balance changes are exact
+    await program.methods
+      .withdrawFees()
+      .accounts({
+        treasury: platform.publicKey,
+        event: eventPDA,
+        feeDestination: platform.publicKey,
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        recipients.map((r) => ({ pubkey: r.publicKey, isSigner: false, isWritable: true }))
+      )
+      .signers([platform])
+      .rpc();
+
+    event = await program.account.event.fetch(eventPDA);
+    expect(event.accruedFees.toNumber()).to.equal(0);
+
+    const after = await Promise.all(recipients.map((r) => provider.connection.getBalance(r.publicKey)));
+    const received = after.map((balance, i) => balance - before[i]);
+
+    const creatorShare = Math.floor((fee * 2000) / 10000);
+    const affiliateShare = Math.floor((fee * 1000) / 10000);
+
+    console.log("\n💵 Fee split:", received.join(" / "), "lamports");
+    expect(received[1]).to.equal(creatorShare);
+    expect(received[2]).to.equal(affiliateShare);
+    // Platform gets its share plus the rounding remainder
+    expect(received[0]).to.equal(fee - creatorShare - affiliateShare);
+  });
+});
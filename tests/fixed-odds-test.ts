@@ -0,0 +1,182 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Fixed Odds Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const ODDS_A = 25000; // 2.5
+  const ODDS_B = 16000; // 1.6
+  const BANKROLL = 2 * LAMPORTS_PER_SOL;
+
+  let backerA: Keypair;
+  let backerB: Keypair;
+  let eventPDA: PublicKey;
+
+  const placeBet = (user: Keypair, outcome: any, amount: number) =>
+    program.methods
+      .createBet(outcome, new BN(amount))
+      .accounts({
+        authority: user.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: user.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+  const betPDA = (user: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), user.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  it("Setup: Create event, post odds and fund the bankroll", async () => {
+    backerA = Keypair.generate();
+    backerB = Keypair.generate();
+    for (const user of [backerA, backerB]) {
+      const airdrop = await provider.connection.requestAirdrop(user.publicKey, 5 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        0,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .setOdds(ODDS_A, ODDS_B)
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    await program.methods
+      .fundBankroll(new BN(BANKROLL))
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        authorityTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.marketType).to.deep.equal({ fixedOdds: {} });
+    expect(event.bankroll.toNumber()).to.equal(BANKROLL);
+  });
+
+  it("Locks in the posted odds at bet time", async () => {
+    await placeBet(backerA, { winA: {} }, 1 * LAMPORTS_PER_SOL);
+
+    // Later odds changes do not affect existing bets
+    await program.methods
+      .setOdds(20000, ODDS_B)
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const bet = await program.account.bet.fetch(betPDA(backerA));
+    expect(bet.oddsBps).to.equal(ODDS_A);
+    expect(bet.potentialPayout.toNumber()).to.equal(2.5 * LAMPORTS_PER_SOL);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.liabilityA.toNumber()).to.equal(2.5 * LAMPORTS_PER_SOL);
+  });
+
+  it("Rejects bets beyond the bankroll's capacity", async () => {
+    // Paying 1.6x on 6 SOL needs 9.6 SOL; the book only holds 2 + 1 + 6
+    try {
+      await placeBet(backerB, { winB: {} }, 6 * LAMPORTS_PER_SOL);
+      throw new Error("Bet beyond bankroll should have failed");
+    } catch (error) {
+      expect(error.toString()).to.match(/BankrollExceeded|insufficient/);
+    }
+
+    await placeBet(backerB, { winB: {} }, 1 * LAMPORTS_PER_SOL);
+  });
+
+  it("Pays the locked-in payout and returns the rest to the house", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const before = await provider.connection.getBalance(backerA.publicKey);
+    await program.methods
+      .settleBet()
+      .accounts({
+        authority: backerA.publicKey,
+        bet: betPDA(backerA),
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: backerA.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([backerA])
+      .rpc();
+    const received = (await provider.connection.getBalance(backerA.publicKey)) - before;
+    expect(received).to.equal(2.5 * LAMPORTS_PER_SOL);
+
+    // 2 SOL bankroll + 2 SOL stakes - 2.5 SOL paid
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.bankroll.toNumber()).to.equal(1.5 * LAMPORTS_PER_SOL);
+    expect(event.settlementFinalized).to.be.true;
+
+    await program.methods
+      .withdrawBankroll(event.bankroll)
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        authorityTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const info = await provider.connection.getAccountInfo(eventPDA);
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(info!.data.length);
+    expect(info!.lamports).to.equal(rent);
+  });
+});
@@ -0,0 +1,266 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Fixture Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const STAKE = 1 * LAMPORTS_PER_SOL;
+
+  let bettor: Keypair;
+  let fixturePDA: PublicKey;
+  let eventPDAs: PublicKey[];
+
+  const newEvent = async () => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        100,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return eventPDA;
+  };
+
+  it("Setup: Create a fixture with two markets and a bet on each", async () => {
+    bettor = Keypair.generate();
+    const airdrop = await provider.connection.requestAirdrop(bettor.publicKey, 5 * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(airdrop, "confirmed");
+
+    const fixtureId = Math.floor(Math.random() * 1000000);
+    const fixtureIdBuffer = Buffer.alloc(8);
+    fixtureIdBuffer.writeUInt32LE(fixtureId, 0);
+    [fixturePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fixture"), fixtureIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createFixture(new BN(fixtureId), "Team A", "Team B", "Cup", new BN(Math.floor(Date.now() / 1000) + 3600))
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+
+    eventPDAs = [await newEvent(), await newEvent()];
+    for (const eventPDA of eventPDAs) {
+      await program.methods
+        .addFixtureMarket()
+        .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA, event: eventPDA })
+        .rpc();
+
+      await program.methods
+        .createBet({ winA: {} }, new BN(STAKE))
+        .accounts({
+          authority: bettor.publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettor.publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettor])
+        .rpc();
+    }
+
+    const fixture = await program.account.fixture.fetch(fixturePDA);
+    expect(fixture.league).to.equal("Cup");
+    expect(fixture.marketCount).to.equal(2);
+  });
+
+  it("Rejects attaching a market that already has bets", async () => {
+    try {
+      await program.methods
+        .addFixtureMarket()
+        .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA, event: eventPDAs[0] })
+        .rpc();
+      throw new Error("Attaching a market with bets should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidFixture");
+    }
+  });
+
+  it("Keeps fixture markets voidable", async () => {
+    // An AMM market cannot be attached, and an attached market cannot become one
+    const ammEvent = await newEvent();
+    await program.methods
+      .seedLiquidity(new BN(STAKE))
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: ammEvent,
+        eventVault: ammEvent,
+        authorityTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
+        eventTokenVault: ammEvent, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    try {
+      await program.methods
+        .addFixtureMarket()
+        .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA, event: ammEvent })
+        .rpc();
+      throw new Error("Attaching an AMM market should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMarketType");
+    }
+
+    const secondFixtureId = Math.floor(Math.random() * 1000000);
+    const secondFixtureIdBuffer = Buffer.alloc(8);
+    secondFixtureIdBuffer.writeUInt32LE(secondFixtureId, 0);
+    const [secondFixturePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fixture"), secondFixtureIdBuffer],
+      PROGRAM_ID
+    );
+    await program.methods
+      .createFixture(new BN(secondFixtureId), "Team A", "Team B", "Cup", new BN(Math.floor(Date.now() / 1000) + 3600))
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+
+    const attached = await newEvent();
+    await program.methods
+      .addFixtureMarket()
+      .accounts({ authority: provider.wallet.publicKey, fixture: secondFixturePDA, event: attached })
+      .rpc();
+    try {
+      await program.methods
+        .seedLiquidity(new BN(STAKE))
+        .accounts({
+          authority: provider.wallet.publicKey,
+          event: attached,
+          eventVault: attached,
+          authorityTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
+          eventTokenVault: attached, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      throw new Error("Converting a fixture market to an AMM should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMarketType");
+    }
+  });
+
+  it("Resolves fixture markets only through the fixture", async () => {
+    for (const method of ["announceWinner", "voidEvent"]) {
+      try {
+        const builder = method === "announceWinner"
+          ? program.methods.announceWinner({ winA: {} })
+          : program.methods.voidEvent();
+        await builder
+          .accounts({ authority: provider.wallet.publicKey, event: eventPDAs[0] })
+          .rpc();
+        throw new Error(`${method} on a fixture market should have failed`);
+      } catch (error) {
+        expect(error.toString()).to.include("FixtureMarket");
+      }
+    }
+  });
+
+  it("Cancelling the fixture voids every market", async () => {
+    await program.methods
+      .cancelFixture()
+      .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA })
+      .remainingAccounts(eventPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+    const fixture = await program.account.fixture.fetch(fixturePDA);
+    expect(fixture.status).to.deep.equal({ cancelled: {} });
+
+    for (const eventPDA of eventPDAs) {
+      const event = await program.account.event.fetch(eventPDA);
+      expect(event.outcome).to.deep.equal({ invalid: {} });
+      expect(event.bettingOpen).to.be.false;
+    }
+  });
+
+  it("Rejects a score report on a cancelled fixture", async () => {
+    try {
+      await program.methods
+        .reportScore(1, 0)
+        .accounts({ authority: provider.wallet.publicKey, fixture: fixturePDA })
+        .remainingAccounts(eventPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+        .rpc();
+      throw new Error("Reporting on a cancelled fixture should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("FixtureNotScheduled");
+    }
+  });
+
+  it("Closes a fixture market only alongside its settled fixture", async () => {
+    const fixtureId = Math.floor(Math.random() * 1000000);
+    const fixtureIdBuffer = Buffer.alloc(8);
+    fixtureIdBuffer.writeUInt32LE(fixtureId, 0);
+    const [closingFixturePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fixture"), fixtureIdBuffer],
+      PROGRAM_ID
+    );
+    await program.methods
+      .createFixture(new BN(fixtureId), "Team A", "Team B", "Cup", new BN(Math.floor(Date.now() / 1000) + 3600))
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+
+    const market = await newEvent();
+    await program.methods
+      .addFixtureMarket()
+      .accounts({ authority: provider.wallet.publicKey, fixture: closingFixturePDA, event: market })
+      .rpc();
+    await program.methods
+      .cancelFixture()
+      .accounts({ authority: provider.wallet.publicKey, fixture: closingFixturePDA })
+      .remainingAccounts([{ pubkey: market, isSigner: false, isWritable: true }])
+      .rpc();
+
+    const closeAccounts = {
+      authority: provider.wallet.publicKey,
+      event: market,
+      eventTokenVault: market, // Dummy for SOL betting
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+    try {
+      await program.methods.closeEvent().accounts(closeAccounts).rpc();
+      throw new Error("Closing a fixture market without its fixture should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("FixtureMarket");
+    }
+
+    await program.methods
+      .closeEvent()
+      .accounts(closeAccounts)
+      .remainingAccounts([{ pubkey: closingFixturePDA, isSigner: false, isWritable: false }])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(market)).to.be.null;
+  });
+});
@@ -0,0 +1,199 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+// Mirrors `merkle::PayoutTree`: prefixed sha256 leaves, sorted-pair nodes
+const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+
+const payoutLeaf = (index: number, bettor: PublicKey, amount: BN) => {
+  const indexBuffer = Buffer.alloc(4);
+  indexBuffer.writeUInt32LE(index, 0);
+  return sha256(Buffer.from([0]), indexBuffer, bettor.toBuffer(), amount.toArrayLike(Buffer, "le", 8));
+};
+
+const hashPair = (a: Buffer, b: Buffer) =>
+  Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+
+const buildTree = (leaves: Buffer[]) => {
+  const layers = [leaves];
+  while (layers[layers.length - 1].length > 1) {
+    const layer = layers[layers.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < layer.length; i += 2) {
+      next.push(i + 1 < layer.length ? hashPair(layer[i], layer[i + 1]) : layer[i]);
+    }
+    layers.push(next);
+  }
+  const proof = (index: number) => {
+    const siblings: number[][] = [];
+    for (const layer of layers.slice(0, -1)) {
+      if ((index ^ 1) < layer.length) siblings.push([...layer[index ^ 1]]);
+      index = Math.floor(index / 2);
+    }
+    return siblings;
+  };
+  return { root: layers[layers.length - 1][0], proof };
+};
+
+describe("Merkle Payout Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const bets: [any, number][] = [
+    [{ winA: {} }, 1 * LAMPORTS_PER_SOL],
+    [{ winA: {} }, 0.5 * LAMPORTS_PER_SOL],
+    [{ winA: {} }, 0.25 * LAMPORTS_PER_SOL],
+    [{ winB: {} }, 2 * LAMPORTS_PER_SOL],
+  ];
+
+  let bettors: Keypair[];
+  let eventPDA: PublicKey;
+  let claimsPDA: PublicKey;
+  let payouts: BN[];
+  let tree: ReturnType<typeof buildTree>;
+
+  const claim = (index: number, amount: BN, proof: number[][]) =>
+    program.methods
+      .claimWithProof(index, amount, proof)
+      .accounts({
+        claimant: bettors[index].publicKey,
+        event: eventPDA,
+        claimBitmap: claimsPDA,
+        eventVault: eventPDA,
+        userTokenAccount: bettors[index].publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([bettors[index]])
+      .rpc();
+
+  it("Setup: Create SOL event, place bets and announce the winner", async () => {
+    bettors = bets.map(() => Keypair.generate());
+    for (const wallet of bettors) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 3 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    [claimsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claims"), eventPDA.toBuffer()],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        300,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    for (const [i, [outcome, amount]] of bets.entries()) {
+      await program.methods
+        .createBet(outcome, new BN(amount))
+        .accounts({
+          authority: bettors[i].publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[i].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettors[i]])
+        .rpc();
+    }
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+  });
+
+  it("Resolver posts the payout root", async () => {
+    // Winners are the first three bettors; leaf index matches bettor index
+    payouts = await Promise.all(
+      bettors.slice(0, 3).map(async (bettor) => {
+        const [bet] = PublicKey.findProgramAddressSync(
+          [Buffer.from("bet"), eventPDA.toBuffer(), bettor.publicKey.toBuffer()],
+          PROGRAM_ID
+        );
+        const quote = await program.methods.quoteSettlement().accounts({ event: eventPDA, bet }).view();
+        return quote.payout;
+      })
+    );
+    tree = buildTree(payouts.map((amount, i) => payoutLeaf(i, bettors[i].publicKey, amount)));
+    const totalPayout = payouts.reduce((sum, amount) => sum.add(amount), new BN(0));
+
+    await program.methods
+      .postPayoutRoot([...tree.root], payouts.length, totalPayout)
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+        claimBitmap: claimsPDA,
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.merkleSettlement).to.be.true;
+    expect(event.unclaimedPayouts.toString()).to.equal(totalPayout.toString());
+  });
+
+  it("Winners claim with proofs", async () => {
+    for (const i of payouts.keys()) {
+      const before = await provider.connection.getBalance(bettors[i].publicKey);
+      await claim(i, payouts[i], tree.proof(i));
+      const after = await provider.connection.getBalance(bettors[i].publicKey);
+      expect(after - before).to.equal(payouts[i].toNumber());
+    }
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.unclaimedPayouts.toNumber()).to.equal(0);
+  });
+
+  it("Rejects double claims and forged amounts", async () => {
+    try {
+      await claim(0, payouts[0], tree.proof(0));
+      throw new Error("Double claim should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("PayoutAlreadyClaimed");
+    }
+
+    try {
+      await claim(3, new BN(LAMPORTS_PER_SOL), tree.proof(2));
+      throw new Error("Forged claim should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMerkleProof");
+    }
+  });
+});
@@ -0,0 +1,302 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Parlay Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const BANKROLL = 5 * LAMPORTS_PER_SOL;
+  const STAKE = 1 * LAMPORTS_PER_SOL;
+  const LEG_ODDS = [20000, 15000, 30000];
+
+  let house: Keypair;
+  let bettor: Keypair;
+  let poolPDA: PublicKey;
+  let parlayPDA: PublicKey;
+  let eventPDAs: PublicKey[];
+
+  const legAccounts = () =>
+    eventPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+
+  it("Setup: Create fixed-odds events and a funded parlay pool", async () => {
+    house = Keypair.generate();
+    bettor = Keypair.generate();
+    for (const wallet of [house, bettor]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 10 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    eventPDAs = [];
+    for (const odds of LEG_ODDS) {
+      const eventId = Math.floor(Math.random() * 1000000);
+      const eventIdBuffer = Buffer.alloc(8);
+      eventIdBuffer.writeUInt32LE(eventId, 0);
+      const [eventPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("event"), eventIdBuffer],
+        PROGRAM_ID
+      );
+      eventPDAs.push(eventPDA);
+
+      await program.methods
+        .createEvent(
+          new BN(eventId),
+          "Team A",
+          "Team B",
+          0,
+          { stake: {} },
+          null,
+          new BN(0),
+          new BN(0),
+          new BN(0)
+        )
+        .accounts({
+          authority: house.publicKey,
+          platformFeeAccount: house.publicKey,
+        })
+        .signers([house])
+        .rpc();
+
+      await program.methods
+        .setOdds(odds, odds)
+        .accounts({ authority: house.publicKey, event: eventPDA })
+        .signers([house])
+        .rpc();
+    }
+
+    [poolPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("parlay_pool"), house.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+    [parlayPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("parlay"), poolPDA.toBuffer(), new BN(0).toArrayLike(Buffer, "le", 8)],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createParlayPool()
+      .accounts({ authority: house.publicKey })
+      .signers([house])
+      .rpc();
+
+    await program.methods
+      .fundParlayPool(new BN(BANKROLL))
+      .accounts({ authority: house.publicKey, pool: poolPDA })
+      .signers([house])
+      .rpc();
+  });
+
+  it("Rejects a parlay with a repeated leg", async () => {
+    try {
+      await program.methods
+        .placeParlay([{ winA: {} }, { winB: {} }], new BN(STAKE))
+        .accounts({ owner: bettor.publicKey, pool: poolPDA, parlay: parlayPDA })
+        .remainingAccounts([legAccounts()[0], legAccounts()[0]])
+        .signers([bettor])
+        .rpc();
+      throw new Error("Repeated leg should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidParlay");
+    }
+  });
+
+  it("Rejects a pari-mutuel leg", async () => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [poolEventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        0,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: house.publicKey,
+        platformFeeAccount: house.publicKey,
+      })
+      .signers([house])
+      .rpc();
+
+    try {
+      await program.methods
+        .placeParlay([{ winA: {} }, { winA: {} }], new BN(STAKE))
+        .accounts({ owner: bettor.publicKey, pool: poolPDA, parlay: parlayPDA })
+        .remainingAccounts([
+          legAccounts()[0],
+          { pubkey: poolEventPDA, isSigner: false, isWritable: true },
+        ])
+        .signers([bettor])
+        .rpc();
+      throw new Error("Pari-mutuel leg should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMarketType");
+    }
+  });
+
+  it("Rejects a leg run by another authority", async () => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [foreignEventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        0,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods
+      .setOdds(1000000, 1000000)
+      .accounts({ authority: provider.wallet.publicKey, event: foreignEventPDA })
+      .rpc();
+
+    // Whoever resolves a leg could otherwise drain the pool's bankroll
+    try {
+      await program.methods
+        .placeParlay([{ winA: {} }, { winA: {} }], new BN(STAKE))
+        .accounts({ owner: bettor.publicKey, pool: poolPDA, parlay: parlayPDA })
+        .remainingAccounts([
+          legAccounts()[0],
+          { pubkey: foreignEventPDA, isSigner: false, isWritable: true },
+        ])
+        .signers([bettor])
+        .rpc();
+      throw new Error("Foreign leg should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("Unauthorized");
+    }
+  });
+
+  it("Places a three-leg parlay at the combined odds", async () => {
+    await program.methods
+      .placeParlay([{ winA: {} }, { winA: {} }, { winB: {} }], new BN(STAKE))
+      .accounts({ owner: bettor.publicKey, pool: poolPDA, parlay: parlayPDA })
+      .remainingAccounts(legAccounts())
+      .signers([bettor])
+      .rpc();
+
+    const combined = LEG_ODDS.reduce((acc, odds) => Math.floor((acc * odds) / 10000), 10000);
+    const parlay = await program.account.parlay.fetch(parlayPDA);
+    expect(parlay.legs.length).to.equal(3);
+    expect(parlay.potentialPayout.toNumber()).to.equal((STAKE * combined) / 10000);
+
+    const pool = await program.account.parlayPool.fetch(poolPDA);
+    expect(pool.openStake.toNumber()).to.equal(STAKE);
+    expect(pool.liability.toNumber()).to.equal(parlay.potentialPayout.toNumber());
+  });
+
+  it("Refuses to settle while a leg is unresolved", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: house.publicKey, event: eventPDAs[0] })
+      .signers([house])
+      .rpc();
+
+    try {
+      await program.methods
+        .settleParlay()
+        .accounts({ owner: bettor.publicKey, pool: poolPDA, parlay: parlayPDA })
+        .remainingAccounts(legAccounts())
+        .signers([bettor])
+        .rpc();
+      throw new Error("Settlement should have failed with open legs");
+    } catch (error) {
+      expect(error.toString()).to.include("EventNotSettled");
+    }
+
+    // The resolved leg stays open until the parlay reads its result
+    try {
+      await program.methods
+        .closeEvent()
+        .accounts({
+          authority: house.publicKey,
+          event: eventPDAs[0],
+          eventTokenVault: eventPDAs[0], // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([house])
+        .rpc();
+      throw new Error("Closing a leg with open parlays should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("OpenParlays");
+    }
+  });
+
+  it("Drops the void leg and pays the remaining legs", async () => {
+    await program.methods
+      .voidEvent()
+      .accounts({ authority: house.publicKey, event: eventPDAs[1] })
+      .signers([house])
+      .rpc();
+    await program.methods
+      .announceWinner({ winB: {} })
+      .accounts({ authority: house.publicKey, event: eventPDAs[2] })
+      .signers([house])
+      .rpc();
+
+    const voided = await program.account.event.fetch(eventPDAs[1]);
+    expect(voided.outcome).to.deep.equal({ invalid: {} });
+
+    const before = await provider.connection.getBalance(bettor.publicKey);
+    await program.methods
+      .settleParlay()
+      .accounts({ owner: bettor.publicKey, pool: poolPDA, parlay: parlayPDA })
+      .remainingAccounts(legAccounts())
+      .signers([bettor])
+      .rpc();
+    const after = await provider.connection.getBalance(bettor.publicKey);
+
+    const payout = (STAKE * LEG_ODDS[0] * LEG_ODDS[2]) / 10000 / 10000;
+    expect(after).to.be.greaterThanOrEqual(before + payout);
+
+    const pool = await program.account.parlayPool.fetch(poolPDA);
+    expect(pool.openStake.toNumber()).to.equal(0);
+    expect(pool.liability.toNumber()).to.equal(0);
+    expect(pool.bankroll.toNumber()).to.equal(BANKROLL + STAKE - payout);
+    expect(await provider.connection.getAccountInfo(parlayPDA)).to.be.null;
+
+    await program.methods
+      .closeEvent()
+      .accounts({
+        authority: house.publicKey,
+        event: eventPDAs[0],
+        eventTokenVault: eventPDAs[0], // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([house])
+      .rpc();
+  });
+});
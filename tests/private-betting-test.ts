@@ -0,0 +1,215 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import { createHash, randomBytes } from "crypto";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+
+// One-byte encodings of the outcomes
+const WIN_A = 2;
+const WIN_B = 3;
+
+describe("Private Betting Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const REVEAL_WINDOW = 3;
+  // The last bettor never reveals
+  const bets: [number, any, number][] = [
+    [1 * LAMPORTS_PER_SOL, { winA: {} }, WIN_A],
+    [2 * LAMPORTS_PER_SOL, { winB: {} }, WIN_B],
+    [1 * LAMPORTS_PER_SOL, { winA: {} }, WIN_A],
+  ];
+
+  let bettors: Keypair[];
+  let salts: Buffer[];
+  let eventPDA: PublicKey;
+
+  const commitmentPDA = (bettor: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("commitment"), eventPDA.toBuffer(), bettor.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  const revealBet = (i: number, salt: Buffer) =>
+    program.methods
+      .revealBet(bets[i][1], Array.from(salt))
+      .accounts({ authority: bettors[i].publicKey, event: eventPDA })
+      .signers([bettors[i]])
+      .rpc();
+
+  it("Setup: Create a private event", async () => {
+    bettors = bets.map(() => Keypair.generate());
+    salts = bets.map(() => randomBytes(32));
+    for (const wallet of bettors) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 5 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        100,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .setPrivateBetting(new BN(REVEAL_WINDOW), false)
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+  });
+
+  it("Rejects public bets on a private event", async () => {
+    try {
+      await program.methods
+        .createBet({ winA: {} }, new BN(LAMPORTS_PER_SOL))
+        .accounts({
+          authority: bettors[0].publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[0].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettors[0]])
+        .rpc();
+      throw new Error("Public bet should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("PrivateBetting");
+    }
+  });
+
+  it("Escrows committed stakes without revealing sides", async () => {
+    for (const [i, [amount, , outcomeByte]] of bets.entries()) {
+      const commitment = sha256(
+        eventPDA.toBuffer(),
+        bettors[i].publicKey.toBuffer(),
+        Buffer.from([outcomeByte]),
+        salts[i]
+      );
+      await program.methods
+        .commitBet(Array.from(commitment), new BN(amount))
+        .accounts({
+          authority: bettors[i].publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettors[i].publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([bettors[i]])
+        .rpc();
+    }
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.committedAmount.toNumber()).to.equal(4 * LAMPORTS_PER_SOL);
+    expect(event.winAAmount.toNumber()).to.equal(0);
+    expect(event.winBAmount.toNumber()).to.equal(0);
+  });
+
+  it("Only accepts matching reveals once betting closes", async () => {
+    try {
+      await revealBet(0, salts[0]);
+      throw new Error("Reveal before close should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("RevealNotOpen");
+    }
+
+    await program.methods
+      .closeBetting()
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    try {
+      await revealBet(0, salts[1]);
+      throw new Error("Reveal with the wrong salt should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidCommitment");
+    }
+
+    await revealBet(0, salts[0]);
+    await revealBet(1, salts[1]);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.winAAmount.toNumber()).to.equal(bets[0][0]);
+    expect(event.winBAmount.toNumber()).to.equal(bets[1][0]);
+    expect(event.committedAmount.toNumber()).to.equal(bets[2][0]);
+  });
+
+  it("Waits for the reveal window before resolving", async () => {
+    try {
+      await program.methods
+        .announceWinner({ winA: {} })
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+        .rpc();
+      throw new Error("Resolving during the reveal window should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("RevealPending");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, (REVEAL_WINDOW + 2) * 1000));
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+  });
+
+  it("Refunds unrevealed stakes after the window", async () => {
+    const bettor = bettors[2];
+    const balanceBefore = await provider.connection.getBalance(bettor.publicKey);
+    await program.methods
+      .expireCommitment()
+      .accounts({
+        event: eventPDA,
+        commitment: commitmentPDA(bettor),
+        owner: bettor.publicKey,
+        ownerTokenAccount: bettor.publicKey, // Dummy for SOL betting
+        eventVault: eventPDA,
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(bettor.publicKey);
+
+    // The stake plus the commitment account's rent
+    expect(balanceAfter - balanceBefore).to.be.greaterThan(bets[2][0]);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.committedAmount.toNumber()).to.equal(0);
+  });
+});
@@ -0,0 +1,148 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Payout Quote Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const FEE_BPS = 500; // 5%
+  const STAKE = 1 * LAMPORTS_PER_SOL;
+  const OTHER_SIDE = 3 * LAMPORTS_PER_SOL;
+
+  let user: Keypair;
+  let eventPDA: PublicKey;
+  let betPDA: PublicKey;
+
+  it("Setup: Create SOL event with a bet on each side", async () => {
+    user = Keypair.generate();
+    const other = Keypair.generate();
+    for (const wallet of [user, other]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 5 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+    [betPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), user.publicKey.toBuffer()],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        FEE_BPS,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .createBet({ winB: {} }, new BN(OTHER_SIDE))
+      .accounts({
+        authority: other.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: other.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([other])
+      .rpc();
+  });
+
+  it("Quotes a prospective bet without changing state", async () => {
+    const quote = await program.methods
+      .quotePayout({ winA: {} }, new BN(STAKE))
+      .accounts({ event: eventPDA })
+      .view();
+
+    const fee = (STAKE * FEE_BPS) / 10000;
+    const netStake = STAKE - fee;
+    console.log("\n🔮 Quote:", quote.payout.toNumber(), "lamports at", quote.impliedOddsBps.toNumber() / 10000, "x");
+    expect(quote.fee.toNumber()).to.equal(fee);
+    expect(quote.netStake.toNumber()).to.equal(netStake);
+    expect(quote.payout.toNumber()).to.equal(netStake + Math.floor((netStake * OTHER_SIDE) / STAKE));
+    // 4 SOL total pool over a 1 SOL side
+    expect(quote.impliedOddsBps.toNumber()).to.equal(40000);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.winAAmount.toNumber()).to.equal(0);
+  });
+
+  it("Quotes an existing bet and matches the actual settlement", async () => {
+    await program.methods
+      .createBet({ winA: {} }, new BN(STAKE))
+      .accounts({
+        authority: user.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: user.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const quote = await program.methods
+      .quoteSettlement()
+      .accounts({ event: eventPDA, bet: betPDA })
+      .view();
+
+    const before = await provider.connection.getBalance(user.publicKey);
+    await program.methods
+      .settleBet()
+      .accounts({
+        authority: user.publicKey,
+        bet: betPDA,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: user.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+    const received = (await provider.connection.getBalance(user.publicKey)) - before;
+
+    expect(received).to.equal(quote.payout.toNumber());
+  });
+});
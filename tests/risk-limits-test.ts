@@ -0,0 +1,176 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Pool Caps and Circuit Breaker Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  let users: Keypair[];
+  let eventPDA: PublicKey;
+
+  const placeBet = (user: Keypair, outcome: any, amount: number) =>
+    program.methods
+      .createBet(outcome, new anchor.BN(amount))
+      .accounts({
+        authority: user.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: user.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+  it("Setup: Create SOL event with a side cap and a 3:1 breaker", async () => {
+    users = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    for (const user of users) {
+      const airdrop = await provider.connection.requestAirdrop(user.publicKey, 3 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new anchor.BN(eventId),
+        "Team A",
+        "Team B",
+        300,
+        { stake: {} },
+        null,
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .updateRiskLimits(
+        new anchor.BN(2 * LAMPORTS_PER_SOL), // Max per side
+        new anchor.BN(0), // No total cap
+        30000, // Pause when one side is more than 3x the other
+        new anchor.BN(1 * LAMPORTS_PER_SOL) // Breaker arms at 1 SOL total
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+      })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.maxSidePool.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+    expect(event.maxImbalanceBps).to.equal(30000);
+  });
+
+  it("Rejects bets above the side cap", async () => {
+    try {
+      await placeBet(users[0], { winA: {} }, 2 * LAMPORTS_PER_SOL + 1);
+      throw new Error("Bet above the side cap should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("SidePoolCapExceeded");
+    }
+  });
+
+  it("Pauses betting when one side exceeds the imbalance ratio", async () => {
+    await placeBet(users[0], { winA: {} }, 0.3 * LAMPORTS_PER_SOL);
+    await placeBet(users[1], { winB: {} }, 0.3 * LAMPORTS_PER_SOL);
+
+    let event = await program.account.event.fetch(eventPDA);
+    expect(event.bettingOpen).to.be.true;
+
+    // 1.3 SOL vs 0.3 SOL trips the 3:1 breaker
+    await placeBet(users[2], { winA: {} }, 1 * LAMPORTS_PER_SOL);
+
+    event = await program.account.event.fetch(eventPDA);
+    expect(event.bettingOpen).to.be.false;
+    console.log("   ✅ Betting paused by imbalance circuit breaker");
+  });
+
+  it("Authority resumes betting", async () => {
+    await program.methods
+      .resumeBetting()
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+      })
+      .rpc();
+
+    // Bets on the lighter side rebalance the pool and never trip the breaker
+    await placeBet(users[1], { winB: {} }, 0.2 * LAMPORTS_PER_SOL);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.bettingOpen).to.be.true;
+  });
+
+  it("Keeps a breaker with no minimum pool open for the first bet", async () => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new anchor.BN(eventId),
+        "Team A",
+        "Team B",
+        300,
+        { stake: {} },
+        null,
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .updateRiskLimits(
+        new anchor.BN(0), // No side cap
+        new anchor.BN(0), // No total cap
+        30000, // Pause when one side is more than 3x the other
+        new anchor.BN(0) // Breaker armed from the first bet
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        event: eventPDA,
+      })
+      .rpc();
+
+    await placeBet(users[0], { winA: {} }, 0.5 * LAMPORTS_PER_SOL);
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.bettingOpen).to.be.true;
+  });
+});
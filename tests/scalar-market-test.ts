@@ -0,0 +1,214 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Scalar Market Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const FEE_BPS = 100; // 1% of each gross payout
+  const LONG_STAKE = 1 * LAMPORTS_PER_SOL;
+  const SHORT_STAKE = 3 * LAMPORTS_PER_SOL;
+  // Total rounds on [0, 12]; resolves at 9, so longs take 75% of the pool
+  const RANGE = { min: new BN(0), max: new BN(12) };
+  const RESOLVED_VALUE = 9;
+
+  let long: Keypair;
+  let short: Keypair;
+  let eventPDA: PublicKey;
+
+  const betPDA = (bettor: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), bettor.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  const settleAccounts = (bettor: Keypair) => ({
+    authority: bettor.publicKey,
+    bet: betPDA(bettor),
+    event: eventPDA,
+    eventVault: eventPDA,
+    userTokenAccount: bettor.publicKey, // Dummy for SOL betting
+    eventTokenVault: eventPDA, // Dummy for SOL betting
+    tokenMint: eventPDA, // Dummy for SOL betting
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  it("Setup: Create a scalar market and take both sides", async () => {
+    long = Keypair.generate();
+    short = Keypair.generate();
+    for (const wallet of [long, short]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 5 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Long",
+        "Short",
+        FEE_BPS,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .setMarketKind({ scalar: RANGE })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    for (const [user, outcome, amount] of [
+      [long, { winA: {} }, LONG_STAKE],
+      [short, { winB: {} }, SHORT_STAKE],
+    ] as [Keypair, any, number][]) {
+      await program.methods
+        .createBet(outcome, new BN(amount))
+        .accounts({
+          authority: user.publicKey,
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: user.publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+  });
+
+  it("Only resolves from a value, not a winner", async () => {
+    try {
+      await program.methods
+        .announceWinner({ winA: {} })
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+        .rpc();
+      throw new Error("Announcing a winner on a scalar market should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidMarketType");
+    }
+
+    await program.methods
+      .resolveScalar(new BN(RESOLVED_VALUE))
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.resolvedValue.toNumber()).to.equal(RESOLVED_VALUE);
+  });
+
+  it("Pays both sides their linear share of the pool", async () => {
+    const total = LONG_STAKE + SHORT_STAKE;
+    const longPot = Math.floor((total * RESOLVED_VALUE) / 12);
+    const shortPot = total - longPot;
+    const net = (gross: number) => gross - Math.floor((gross * FEE_BPS) / 10000);
+
+    for (const bettor of [long, short]) {
+      await program.methods
+        .settleBet()
+        .accounts(settleAccounts(bettor))
+        .signers([bettor])
+        .rpc();
+    }
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.totalPaidOut.toNumber()).to.equal(net(longPot) + net(shortPot));
+    expect(event.settlementFinalized).to.be.true;
+    expect(event.totalPaidOut.toNumber() + event.totalFees.toNumber()).to.equal(total);
+  });
+
+  it("Refunds every stake when one side of the range is empty", async () => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Long",
+        "Short",
+        FEE_BPS,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .setMarketKind({ scalar: RANGE })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    await program.methods
+      .createBet({ winA: {} }, new BN(LONG_STAKE))
+      .accounts({
+        authority: long.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: long.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([long])
+      .rpc();
+
+    // At the bottom of the range the empty short side would own the whole pool
+    await program.methods
+      .resolveScalar(RANGE.min)
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    await program.methods
+      .settleBet()
+      .accounts(settleAccounts(long))
+      .signers([long])
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.totalPaidOut.toNumber()).to.equal(LONG_STAKE);
+    expect(event.totalFees.toNumber()).to.equal(0);
+    expect(event.settlementFinalized).to.be.true;
+  });
+});
@@ -0,0 +1,191 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Stake Weighting Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const FEE_BPS = 100; // 1% of net winnings
+  const FLOOR_BPS = 2000;
+  const bets: [number, any][] = [
+    [1 * LAMPORTS_PER_SOL, { winA: {} }],
+    [2 * LAMPORTS_PER_SOL, { winA: {} }],
+    [3 * LAMPORTS_PER_SOL, { winB: {} }],
+  ];
+
+  let bettors: Keypair[];
+  let eventPDA: PublicKey;
+
+  const chainTime = async () =>
+    (await provider.connection.getBlockTime(await provider.connection.getSlot())) as number;
+
+  const betPDA = (event: PublicKey, bettor: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), event.toBuffer(), bettor.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  const newEvent = async () => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [event] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        FEE_BPS,
+        { netWinnings: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return event;
+  };
+
+  const placeBet = (event: PublicKey, bettor: Keypair, outcome: any, amount: number) =>
+    program.methods
+      .createBet(outcome, new BN(amount))
+      .accounts({
+        authority: bettor.publicKey,
+        event,
+        eventVault: event,
+        userTokenAccount: bettor.publicKey, // Dummy for SOL betting
+        eventTokenVault: event, // Dummy for SOL betting
+        tokenMint: event, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bettor])
+      .rpc();
+
+  it("Setup: Create a weighted event partway through its decay window", async () => {
+    bettors = bets.map(() => Keypair.generate());
+    for (const wallet of bettors) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 5 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    eventPDA = await newEvent();
+    const now = await chainTime();
+    await program.methods
+      .setStakeWeighting(new BN(now + 3600), new BN(now - 3600), FLOOR_BPS)
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.weightFloorBps).to.equal(FLOOR_BPS);
+  });
+
+  it("Records decayed weights on each bet and side", async () => {
+    for (const [i, [amount, outcome]] of bets.entries()) {
+      await placeBet(eventPDA, bettors[i], outcome, amount);
+    }
+
+    let weightedA = 0;
+    for (const [i, [amount, outcome]] of bets.entries()) {
+      const bet = await program.account.bet.fetch(betPDA(eventPDA, bettors[i]));
+      const weight = bet.weightedAmount.toNumber();
+      expect(weight).to.be.lessThan(amount);
+      expect(weight).to.be.at.least((amount * FLOOR_BPS) / 10000);
+      if (outcome.winA) {
+        weightedA += weight;
+      }
+    }
+
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.weightedA.toNumber()).to.equal(weightedA);
+  });
+
+  it("Rejects changing the curve once bets are in", async () => {
+    const now = await chainTime();
+    try {
+      await program.methods
+        .setStakeWeighting(new BN(now + 3600), new BN(now), FLOOR_BPS)
+        .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+        .rpc();
+      throw new Error("Changing the curve after bets should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidStakeWeighting");
+    }
+  });
+
+  it("Splits the losing pool by weighted stake", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    const event = await program.account.event.fetch(eventPDA);
+    const losingPool = bets[2][0];
+    let paid = 0;
+    for (const i of [0, 1]) {
+      const bettor = bettors[i];
+      const bet = await program.account.bet.fetch(betPDA(eventPDA, bettor));
+      const share = Math.floor((bet.weightedAmount.toNumber() * losingPool) / event.weightedA.toNumber());
+      paid += bets[i][0] + share - Math.floor((share * FEE_BPS) / 10000);
+
+      await program.methods
+        .settleBet()
+        .accounts({
+          authority: bettor.publicKey,
+          bet: betPDA(eventPDA, bettor),
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: bettor.publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([bettor])
+        .rpc();
+    }
+
+    const settled = await program.account.event.fetch(eventPDA);
+    expect(settled.totalPaidOut.toNumber()).to.equal(paid);
+    expect(settled.settlementFinalized).to.be.true;
+  });
+
+  it("Rejects bets after the betting deadline", async () => {
+    const event = await newEvent();
+    const now = await chainTime();
+    await program.methods
+      .setStakeWeighting(new BN(now + 2), new BN(0), 0)
+      .accounts({ authority: provider.wallet.publicKey, event })
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+
+    try {
+      await placeBet(event, bettors[0], { winA: {} }, LAMPORTS_PER_SOL);
+      throw new Error("Bet after the deadline should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("BettingClosed");
+    }
+  });
+});
@@ -241,7 +241,11 @@ describe("SOL and SPL Token Betting Test", () => {
           opponentA,
           opponentB,
           feeBps,
-          null // null = SOL betting
+          { stake: {} }, // Fee taken from the winning stake
+          null, // null = SOL betting
+          new anchor.BN(0), // No minimum bet
+          new anchor.BN(0), // No maximum bet
+          new anchor.BN(0) // No wallet exposure cap
         )
         .accounts({
           authority: provider.wallet.publicKey,
@@ -463,10 +467,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA1,
         event: solEventPDA,
         eventVault: solEventVault,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet1.publicKey, // Dummy for SOL betting
         eventTokenVault: solEventPDA, // Dummy for SOL betting
-        platformFeeTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
         tokenMint: solEventPDA, // Dummy for SOL betting
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -482,10 +484,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA2,
         event: solEventPDA,
         eventVault: solEventVault,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet2.publicKey, // Dummy for SOL betting
         eventTokenVault: solEventPDA, // Dummy for SOL betting
-        platformFeeTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
         tokenMint: solEventPDA, // Dummy for SOL betting
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -501,10 +501,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA3,
         event: solEventPDA,
         eventVault: solEventVault,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet3.publicKey, // Dummy for SOL betting
         eventTokenVault: solEventPDA, // Dummy for SOL betting
-        platformFeeTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
         tokenMint: solEventPDA, // Dummy for SOL betting
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -520,10 +518,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA4,
         event: solEventPDA,
         eventVault: solEventVault,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet4.publicKey, // Dummy for SOL betting
         eventTokenVault: solEventPDA, // Dummy for SOL betting
-        platformFeeTokenAccount: provider.wallet.publicKey, // Dummy for SOL betting
         tokenMint: solEventPDA, // Dummy for SOL betting
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -618,7 +614,11 @@ describe("SOL and SPL Token Betting Test", () => {
           opponentA,
           opponentB,
           feeBps,
-          tokenMint // Pass the token mint as Option<Pubkey>
+          { stake: {} }, // Fee taken from the winning stake
+          tokenMint, // Pass the token mint as Option<Pubkey>
+          new anchor.BN(0), // No minimum bet
+          new anchor.BN(0), // No maximum bet
+          new anchor.BN(0) // No wallet exposure cap
         )
         .accounts({
           authority: provider.wallet.publicKey,
@@ -847,10 +847,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA1,
         event: tokenEventPDA,
         eventVault: tokenEventPDA,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet1TokenAccount,
         eventTokenVault: eventTokenVault,
-        platformFeeTokenAccount: platformFeeTokenAccount,
         tokenMint: tokenMint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -866,10 +864,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA2,
         event: tokenEventPDA,
         eventVault: tokenEventPDA,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet2TokenAccount,
         eventTokenVault: eventTokenVault,
-        platformFeeTokenAccount: platformFeeTokenAccount,
         tokenMint: tokenMint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -885,10 +881,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA3,
         event: tokenEventPDA,
         eventVault: tokenEventPDA,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet3TokenAccount,
         eventTokenVault: eventTokenVault,
-        platformFeeTokenAccount: platformFeeTokenAccount,
         tokenMint: tokenMint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -904,10 +898,8 @@ describe("SOL and SPL Token Betting Test", () => {
         bet: betPDA4,
         event: tokenEventPDA,
         eventVault: tokenEventPDA,
-        platformFeeAccount: provider.wallet.publicKey,
         userTokenAccount: wallet4TokenAccount,
         eventTokenVault: eventTokenVault,
-        platformFeeTokenAccount: platformFeeTokenAccount,
         tokenMint: tokenMint,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -0,0 +1,280 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+
+describe("Tournament Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const ENTRANTS = ["Alpha", "Bravo", "Charlie", "Delta"];
+  const FEE_BPS = 500;
+  const WINNER_STAKE = 1 * LAMPORTS_PER_SOL;
+  const LOSER_STAKE = 3 * LAMPORTS_PER_SOL;
+
+  let winner: Keypair;
+  let loser: Keypair;
+  let tournamentPDA: PublicKey;
+  let semiFinals: PublicKey[];
+  let finalPDA: PublicKey;
+
+  const newEvent = async (opponentA: string, opponentB: string) => {
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    const [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        opponentA,
+        opponentB,
+        100,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return eventPDA;
+  };
+
+  const newTournament = async (finalEvent: PublicKey) => {
+    const tournamentId = Math.floor(Math.random() * 1000000);
+    const tournamentIdBuffer = Buffer.alloc(8);
+    tournamentIdBuffer.writeUInt32LE(tournamentId, 0);
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tournament"), tournamentIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createTournament(new BN(tournamentId), "Knockout Cup", ENTRANTS, FEE_BPS)
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods
+      .setTournamentFinal()
+      .accounts({ authority: provider.wallet.publicKey, tournament: pda, event: finalEvent })
+      .rpc();
+
+    return pda;
+  };
+
+  const outrightBetPDA = (user: Keypair, tournament: PublicKey = tournamentPDA) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("outright_bet"), tournament.toBuffer(), user.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  it("Setup: Create a four-entrant bracket", async () => {
+    winner = Keypair.generate();
+    loser = Keypair.generate();
+    for (const wallet of [winner, loser]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 10 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const tournamentId = Math.floor(Math.random() * 1000000);
+    const tournamentIdBuffer = Buffer.alloc(8);
+    tournamentIdBuffer.writeUInt32LE(tournamentId, 0);
+    [tournamentPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tournament"), tournamentIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createTournament(new BN(tournamentId), "Knockout Cup", ENTRANTS, FEE_BPS)
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    semiFinals = [await newEvent("Alpha", "Bravo"), await newEvent("Charlie", "Delta")];
+    finalPDA = await newEvent("TBD", "TBD");
+
+    for (const [slot, semiFinal] of semiFinals.entries()) {
+      await program.methods
+        .linkBracketMatch(slot)
+        .accounts({
+          authority: provider.wallet.publicKey,
+          tournament: tournamentPDA,
+          event: semiFinal,
+          nextEvent: finalPDA,
+        })
+        .rpc();
+    }
+
+    await program.methods
+      .setTournamentFinal()
+      .accounts({ authority: provider.wallet.publicKey, tournament: tournamentPDA, event: finalPDA })
+      .rpc();
+
+    const tournament = await program.account.tournament.fetch(tournamentPDA);
+    expect(tournament.entrants).to.deep.equal(ENTRANTS);
+    expect(tournament.finalEvent.toString()).to.equal(finalPDA.toString());
+  });
+
+  it("Takes outright bets on entrants", async () => {
+    for (const [user, entrant, amount] of [
+      [winner, 0, WINNER_STAKE],
+      [loser, 2, LOSER_STAKE],
+    ] as [Keypair, number, number][]) {
+      await program.methods
+        .placeOutrightBet(entrant, new BN(amount))
+        .accounts({ authority: user.publicKey, tournament: tournamentPDA, finalEvent: finalPDA })
+        .signers([user])
+        .rpc();
+    }
+
+    const tournament = await program.account.tournament.fetch(tournamentPDA);
+    expect(tournament.totalPool.toNumber()).to.equal(WINNER_STAKE + LOSER_STAKE);
+    expect(tournament.entrantPools[0].toNumber()).to.equal(WINNER_STAKE);
+  });
+
+  it("Rejects a semi-final result without its next match", async () => {
+    try {
+      await program.methods
+        .announceWinner({ winA: {} })
+        .accounts({ authority: provider.wallet.publicKey, event: semiFinals[0] })
+        .remainingAccounts([{ pubkey: tournamentPDA, isSigner: false, isWritable: true }])
+        .rpc();
+      throw new Error("Missing next match should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("InvalidBracket");
+    }
+  });
+
+  it("Advances semi-final winners into the final", async () => {
+    for (const semiFinal of semiFinals) {
+      await program.methods
+        .announceWinner({ winA: {} })
+        .accounts({ authority: provider.wallet.publicKey, event: semiFinal })
+        .remainingAccounts([
+          { pubkey: tournamentPDA, isSigner: false, isWritable: true },
+          { pubkey: finalPDA, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+    }
+
+    const final = await program.account.event.fetch(finalPDA);
+    expect(final.opponentA).to.equal("Alpha");
+    expect(final.opponentB).to.equal("Charlie");
+  });
+
+  it("Resolves the outright pool when the final is announced", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: finalPDA })
+      .remainingAccounts([{ pubkey: tournamentPDA, isSigner: false, isWritable: true }])
+      .rpc();
+
+    const tournament = await program.account.tournament.fetch(tournamentPDA);
+    expect(tournament.champion).to.equal(0);
+  });
+
+  it("Pays the champion's backers and closes losing bets", async () => {
+    const balanceBefore = await provider.connection.getBalance(winner.publicKey);
+    await program.methods
+      .claimOutright()
+      .accounts({
+        authority: winner.publicKey,
+        tournament: tournamentPDA,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .signers([winner])
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(winner.publicKey);
+
+    // Full pool less the fee on winnings, plus the bet account's rent
+    const expectedPayout = WINNER_STAKE + LOSER_STAKE - (LOSER_STAKE * FEE_BPS) / 10000;
+    expect(balanceAfter - balanceBefore).to.be.greaterThan(expectedPayout - 10000);
+
+    await program.methods
+      .claimOutright()
+      .accounts({
+        authority: loser.publicKey,
+        tournament: tournamentPDA,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .signers([loser])
+      .rpc();
+
+    for (const user of [winner, loser]) {
+      const info = await provider.connection.getAccountInfo(outrightBetPDA(user));
+      expect(info).to.be.null;
+    }
+  });
+
+  it("Refunds outright stakes when the final is voided", async () => {
+    const voidedFinal = await newEvent("Alpha", "Charlie");
+    const voidedTournament = await newTournament(voidedFinal);
+
+    await program.methods
+      .placeOutrightBet(1, new BN(WINNER_STAKE))
+      .accounts({ authority: winner.publicKey, tournament: voidedTournament, finalEvent: voidedFinal })
+      .signers([winner])
+      .rpc();
+
+    // Outright betting closes with the final
+    await program.methods
+      .closeBetting()
+      .accounts({ authority: provider.wallet.publicKey, event: voidedFinal })
+      .rpc();
+    try {
+      await program.methods
+        .placeOutrightBet(1, new BN(WINNER_STAKE))
+        .accounts({ authority: winner.publicKey, tournament: voidedTournament, finalEvent: voidedFinal })
+        .signers([winner])
+        .rpc();
+      throw new Error("Outright bet after the final closed should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("BettingClosed");
+    }
+
+    await program.methods
+      .voidEvent()
+      .accounts({ authority: provider.wallet.publicKey, event: voidedFinal })
+      .rpc();
+    await program.methods
+      .voidTournament()
+      .accounts({ authority: provider.wallet.publicKey, tournament: voidedTournament })
+      .rpc();
+
+    const balanceBefore = await provider.connection.getBalance(winner.publicKey);
+    await program.methods
+      .claimOutright()
+      .accounts({
+        authority: winner.publicKey,
+        tournament: voidedTournament,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .signers([winner])
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(winner.publicKey);
+
+    // The stake plus the bet account's rent
+    expect(balanceAfter - balanceBefore).to.be.greaterThan(WINNER_STAKE - 10000);
+    expect(await provider.connection.getAccountInfo(outrightBetPDA(winner, voidedTournament))).to.be.null;
+  });
+});
@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SportsPredictionMarket } from "../target/types/sports_prediction_market";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+
+describe("Transfer Bet Test", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SportsPredictionMarket as Program<SportsPredictionMarket>;
+  const PROGRAM_ID = program.programId;
+
+  const STAKE = 1 * LAMPORTS_PER_SOL;
+
+  let seller: Keypair;
+  let buyer: Keypair;
+  let eventPDA: PublicKey;
+
+  const betPDA = (owner: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("bet"), eventPDA.toBuffer(), owner.publicKey.toBuffer()],
+      PROGRAM_ID
+    )[0];
+
+  it("Setup: Create SOL event and place a bet", async () => {
+    seller = Keypair.generate();
+    buyer = Keypair.generate();
+    for (const wallet of [seller, buyer]) {
+      const airdrop = await provider.connection.requestAirdrop(wallet.publicKey, 2 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdrop, "confirmed");
+    }
+
+    const eventId = Math.floor(Math.random() * 1000000);
+    const eventIdBuffer = Buffer.alloc(8);
+    eventIdBuffer.writeUInt32LE(eventId, 0);
+    [eventPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), eventIdBuffer],
+      PROGRAM_ID
+    );
+
+    await program.methods
+      .createEvent(
+        new BN(eventId),
+        "Team A",
+        "Team B",
+        0,
+        { stake: {} },
+        null,
+        new BN(0),
+        new BN(0),
+        new BN(0)
+      )
+      .accounts({
+        authority: provider.wallet.publicKey,
+        platformFeeAccount: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .createBet({ winA: {} }, new BN(STAKE))
+      .accounts({
+        authority: seller.publicKey,
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: seller.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([seller])
+      .rpc();
+  });
+
+  it("Moves the bet to the buyer's bet account", async () => {
+    await program.methods
+      .transferBet()
+      .accounts({
+        authority: seller.publicKey,
+        bet: betPDA(seller),
+        event: eventPDA,
+        newOwner: buyer.publicKey,
+      })
+      .signers([seller])
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(betPDA(seller))).to.be.null;
+    const bet = await program.account.bet.fetch(betPDA(buyer));
+    expect(bet.authority.toBase58()).to.equal(buyer.publicKey.toBase58());
+    expect(bet.amount.toNumber()).to.equal(STAKE);
+    expect(bet.outcome).to.deep.equal({ winA: {} });
+  });
+
+  it("Pays the new owner at settlement", async () => {
+    await program.methods
+      .announceWinner({ winA: {} })
+      .accounts({ authority: provider.wallet.publicKey, event: eventPDA })
+      .rpc();
+
+    // The previous owner no longer holds the bet
+    try {
+      await program.methods
+        .settleBet()
+        .accounts({
+          authority: seller.publicKey,
+          bet: betPDA(buyer),
+          event: eventPDA,
+          eventVault: eventPDA,
+          userTokenAccount: seller.publicKey, // Dummy for SOL betting
+          eventTokenVault: eventPDA, // Dummy for SOL betting
+          tokenMint: eventPDA, // Dummy for SOL betting
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([seller])
+        .rpc();
+      throw new Error("Previous owner settlement should have failed");
+    } catch (error) {
+      expect(error.toString()).to.include("Unauthorized");
+    }
+
+    const before = await provider.connection.getBalance(buyer.publicKey);
+    await program.methods
+      .settleBet()
+      .accounts({
+        authority: buyer.publicKey,
+        bet: betPDA(buyer),
+        event: eventPDA,
+        eventVault: eventPDA,
+        userTokenAccount: buyer.publicKey, // Dummy for SOL betting
+        eventTokenVault: eventPDA, // Dummy for SOL betting
+        tokenMint: eventPDA, // Dummy for SOL betting
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([buyer])
+      .rpc();
+
+    const after = await provider.connection.getBalance(buyer.publicKey);
+    expect(after).to.be.greaterThan(before);
+    const event = await program.account.event.fetch(eventPDA);
+    expect(event.totalPaidOut.toNumber()).to.equal(STAKE);
+  });
+});